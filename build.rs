@@ -0,0 +1,52 @@
+//! Generates one test per example file in `examples/dayN`, named
+//! `dayN_<example>`, so a failure names its example and a single example can
+//! be run with `cargo test dayN_<example>`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    let examples = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("examples");
+    println!("cargo:rerun-if-changed={}", examples.display());
+
+    let mut files = Vec::new();
+    for day in fs::read_dir(&examples).into_iter().flatten().flatten() {
+        let name = day.file_name().to_string_lossy().into_owned();
+        let Some(n) = name.strip_prefix("day").and_then(|n| n.parse::<u8>().ok()) else {
+            continue;
+        };
+        for file in fs::read_dir(day.path()).into_iter().flatten().flatten() {
+            let name = file.file_name().to_string_lossy().into_owned();
+            files.push((n, name, file.path()));
+        }
+    }
+    files.sort();
+
+    let mut tests = String::new();
+    for (day, name, path) in files {
+        writeln!(
+            tests,
+            "#[test]\nfn day{}_{}() {{\n    check({}, {:?}, include_str!({:?}));\n}}\n",
+            day,
+            identifier(&name),
+            day,
+            name,
+            path.display().to_string()
+        )
+        .unwrap();
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// `name` with everything but ASCII letters and digits replaced by `_`.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
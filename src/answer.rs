@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::square::Pos;
+
+/// The answer to one part of a puzzle.
+///
+/// Integers are stored as `Int` whenever they fit into an `i64` and as
+/// `BigInt` otherwise, so answers compare equal regardless of the integer
+/// type a day happened to compute them in.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+    Coords(Vec<(i64, i64)>),
+}
+
+impl Answer {
+    fn int(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+
+    /// Whether this answer prints exactly as `expected`, ignoring surrounding whitespace.
+    pub fn matches(&self, expected: &str) -> bool {
+        let printed = self.to_string();
+        printed == expected.trim()
+    }
+}
+
+impl Display for Answer {
+    /// Coordinates print as `x,y`, multiple coordinates are separated by `;`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Coords(cs) => write!(
+                f,
+                "{}",
+                cs.iter().map(|(x, y)| format!("{},{}", x, y)).join(";")
+            ),
+        }
+    }
+}
+
+impl<T: Clone + Into<Answer>> PartialEq<T> for Answer {
+    fn eq(&self, other: &T) -> bool {
+        match (self, &other.clone().into()) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::BigInt(a), Answer::BigInt(b)) => a == b,
+            (Answer::Str(a), Answer::Str(b)) => a == b,
+            (Answer::Coords(a), Answer::Coords(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::int(n as i128)
+            }
+        })*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+macro_rules! from_coord {
+    ($($t:ty),*) => {
+        $(impl From<($t, $t)> for Answer {
+            fn from((x, y): ($t, $t)) -> Self {
+                Answer::Coords(vec![(x as i64, y as i64)])
+            }
+        }
+
+        impl From<Vec<($t, $t)>> for Answer {
+            fn from(cs: Vec<($t, $t)>) -> Self {
+                Answer::Coords(cs.into_iter().map(|(x, y)| (x as i64, y as i64)).collect())
+            }
+        })*
+    };
+}
+
+from_coord!(i32, i64, isize, usize);
+
+impl From<Pos> for Answer {
+    fn from(p: Pos) -> Self {
+        Answer::from((p.x, p.y))
+    }
+}
+
+impl From<Vec<Pos>> for Answer {
+    fn from(ps: Vec<Pos>) -> Self {
+        Answer::from(
+            ps.into_iter()
+                .map(<(isize, isize)>::from)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_owned())
+    }
+}
+
+#[test]
+fn test_int_widths() {
+    assert_eq!(Answer::from(42u8), 42i64);
+    assert_eq!(Answer::from(42usize), Answer::from(42i32));
+    assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+    assert_ne!(Answer::from(42), "42");
+}
+
+#[test]
+fn test_display() {
+    assert!(Answer::from(u64::MAX).matches("18446744073709551615"));
+    assert!(Answer::from((6, 1)).matches("6,1\n"));
+    assert_eq!(
+        Answer::from(vec![(1, 2), (3, -4)]).to_string(),
+        "1,2;3,-4".to_string()
+    );
+}
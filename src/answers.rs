@@ -0,0 +1,121 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory holding one recorded answers file per day.
+const ANSWERS_DIR: &str = "answers";
+
+/// Stable fingerprint of a puzzle input, so answers recorded for one input
+/// are never checked against another. Trailing whitespace is ignored.
+pub fn fingerprint(input: &str) -> String {
+    // 64 bit FNV-1a; std's hashers are not stable across releases.
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{:016x}", hash)
+}
+
+/// Known correct answers for a day.
+///
+/// Stored as lines of `<fingerprint> <part> <answer>`, lines starting
+/// with `#` are comments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    entries: Vec<(String, u8, String)>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .filter_map(|l| {
+                let (fingerprint, rest) = l.split_once(' ')?;
+                let (part, answer) = rest.split_once(' ')?;
+                Some((
+                    fingerprint.to_owned(),
+                    part.parse().ok()?,
+                    answer.to_owned(),
+                ))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Answers recorded for `day`; empty if none were recorded yet.
+    pub fn load(day: u8) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(day)) {
+            Ok(content) => Ok(Self::parse(&content)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, day: u8) -> io::Result<()> {
+        let path = Self::path(day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// `answers/dayN` in the current directory, or in this crate when run from elsewhere.
+    fn path(day: u8) -> PathBuf {
+        let local = PathBuf::from(ANSWERS_DIR);
+        let dir = if local.is_dir() {
+            local
+        } else {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_DIR)
+        };
+        dir.join(format!("day{}", day))
+    }
+
+    pub fn get(&self, fingerprint: &str, part: u8) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(f, p, _)| f == fingerprint && *p == part)
+            .map(|(_, _, a)| a.as_str())
+    }
+
+    /// Records `answer`, replacing any answer previously recorded for the same input and part.
+    pub fn record(&mut self, fingerprint: &str, part: u8, answer: &str) {
+        self.entries
+            .retain(|(f, p, _)| f != fingerprint || *p != part);
+        self.entries
+            .push((fingerprint.to_owned(), part, answer.to_owned()));
+        self.entries.sort();
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# <input fingerprint> <part> <answer>")?;
+        for (fingerprint, part, answer) in &self.entries {
+            writeln!(f, "{} {} {}", fingerprint, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_fingerprint() {
+    assert_eq!(fingerprint(""), "cbf29ce484222325");
+    assert_eq!(fingerprint("125 17\n"), fingerprint("125 17"));
+    assert_ne!(fingerprint("125 17"), fingerprint("125 18"));
+}
+
+#[test]
+fn test_answers_roundtrip() {
+    let mut answers = Answers::default();
+    answers.record("abc", 2, "co,de,ka,ta");
+    answers.record("abc", 1, "7");
+    answers.record("abc", 1, "8");
+    assert_eq!(answers.get("abc", 1), Some("8"));
+    assert_eq!(answers.get("abd", 1), None);
+    assert_eq!(Answers::parse(&answers.to_string()), answers);
+}
@@ -0,0 +1,90 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How many times a stage is run before and while it is measured.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            iterations: 10,
+        }
+    }
+}
+
+/// Summary of the timings of one benchmarked stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot summarize zero samples");
+        samples.sort();
+        let n = samples.len();
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let var = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        Self {
+            samples: n,
+            min: samples[0],
+            median,
+            p95: percentile(&samples, 0.95),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(var.sqrt()),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Runs `f` `config.warmup` times unmeasured, then `config.iterations` times measured.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+#[test]
+fn test_stats() {
+    let ms = |n| Duration::from_millis(n);
+    let stats = Stats::from_samples((1..=20).rev().map(ms).collect());
+    assert_eq!(stats.samples, 20);
+    assert_eq!(stats.min, ms(1));
+    assert_eq!(stats.median, Duration::from_micros(10_500));
+    assert_eq!(stats.p95, ms(19));
+    assert_eq!(stats.mean, Duration::from_micros(10_500));
+
+    let stats = Stats::from_samples(vec![ms(4); 3]);
+    assert_eq!(stats.median, ms(4));
+    assert_eq!(stats.p95, ms(4));
+    assert_eq!(stats.stddev, Duration::ZERO);
+}
@@ -0,0 +1,430 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    process::ExitCode,
+    str::FromStr,
+};
+
+use advent_of_code_24::{
+    answers::{self, Answers},
+    bench::{BenchConfig, Stats},
+    client::{Client, ClientConfig},
+    days::{self, Day},
+    input, scaffold,
+    submit::Verdict,
+    Parts,
+};
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <name|path|->] [--render]
+    aoc bench <day|all> [--part <1|2>] [--input <name|path|->] [--warmup <n>] [--iterations <n>] [--json]
+    aoc verify <day|all> [--part <1|2>] [--input <name|path|->] [--record]
+    aoc new <day> [--html <file>] [--offline]
+    aoc fetch <day>
+    aoc submit <day> <1|2> [--input <name|path|->] [--answer <answer>]
+
+Inputs are read from $AOC_INPUTS, or ./inputs: dayN for the real input, dayN.<name> for others.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => cmd_run(&Args::parse(
+            &args[1..],
+            &["--part", "--input"],
+            &["--render"],
+        )?),
+        Some("verify") => cmd_verify(&Args::parse(
+            &args[1..],
+            &["--part", "--input"],
+            &["--record"],
+        )?),
+        Some("bench") => cmd_bench(&Args::parse(
+            &args[1..],
+            &["--part", "--input", "--warmup", "--iterations"],
+            &["--json"],
+        )?),
+        Some("new") => cmd_new(&Args::parse(&args[1..], &["--html"], &["--offline"])?),
+        Some("fetch") => cmd_fetch(&Args::parse(&args[1..], &[], &[])?),
+        Some("submit") => cmd_submit(&Args::parse(&args[1..], &["--input", "--answer"], &[])?),
+        Some(cmd) => Err(format!("unknown command {}", cmd)),
+        None => Err("missing command".to_owned()),
+    }
+}
+
+fn cmd_run(args: &Args) -> Result<ExitCode, String> {
+    let parts = args.parts()?;
+    let days = args.days()?;
+    for day in &days {
+        if days.len() > 1 {
+            println!("day{}", day.day);
+        }
+        let input = args.input(day)?;
+        let fingerprint = answers::fingerprint(&input);
+        let answers = Answers::load(day.day).map_err(|e| e.to_string())?;
+        for solved in day.solve(&input, parts) {
+            let check = match answers.get(&fingerprint, solved.part) {
+                Some(want) if solved.answer.matches(want) => " (correct)".to_owned(),
+                Some(want) => format!(" (wrong, expected {})", want),
+                None => String::new(),
+            };
+            println!(
+                "part{} in {:.5} seconds: {}{}",
+                solved.part,
+                solved.elapsed.as_secs_f32(),
+                solved.answer,
+                check
+            );
+        }
+        if args.flag("--render") {
+            match day.render(&input) {
+                Some(picture) => print!("{}", picture),
+                None => println!("day{} has nothing to render", day.day),
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn cmd_verify(args: &Args) -> Result<ExitCode, String> {
+    let parts = args.parts()?;
+    let record = args.flag("--record");
+    let mut mismatches = 0;
+    for day in args.days()? {
+        let input = args.input(day)?;
+        let fingerprint = answers::fingerprint(&input);
+        let mut answers = Answers::load(day.day).map_err(|e| e.to_string())?;
+        let mut recorded = false;
+        for solved in day.solve(&input, parts) {
+            let label = format!("day{} part{}", day.day, solved.part);
+            match answers.get(&fingerprint, solved.part) {
+                Some(want) if solved.answer.matches(want) => println!("{}: ok", label),
+                Some(want) => {
+                    mismatches += 1;
+                    println!(
+                        "{}: MISMATCH got {}, expected {}",
+                        label, solved.answer, want
+                    );
+                }
+                None if record => {
+                    answers.record(&fingerprint, solved.part, &solved.answer.to_string());
+                    recorded = true;
+                    println!("{}: recorded {}", label, solved.answer);
+                }
+                None => println!("{}: unrecorded, got {}", label, solved.answer),
+            }
+        }
+        if recorded {
+            answers.save(day.day).map_err(|e| e.to_string())?;
+        }
+    }
+    if mismatches > 0 {
+        println!("{} mismatches", mismatches);
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn cmd_bench(args: &Args) -> Result<ExitCode, String> {
+    let parts = args.parts()?;
+    let default = BenchConfig::default();
+    let config = BenchConfig {
+        warmup: args.value("--warmup")?.unwrap_or(default.warmup),
+        iterations: args.value("--iterations")?.unwrap_or(default.iterations),
+    };
+    let json = args.flag("--json");
+
+    let mut rows = Vec::new();
+    for day in args.days()? {
+        if !json {
+            println!("day{}", day.day);
+        }
+        for (stage, stats) in day.bench(&args.input(day)?, parts, &config) {
+            if json {
+                rows.push(json_row(day.day, stage, &stats));
+            } else {
+                println!(
+                    "  {:<6} min {:>10.3?}  median {:>10.3?}  p95 {:>10.3?}  stddev {:>10.3?}  (n={})",
+                    stage, stats.min, stats.median, stats.p95, stats.stddev, stats.samples
+                );
+            }
+        }
+    }
+    if json {
+        println!("[\n  {}\n]", rows.join(",\n  "));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Scaffolds a day. The example comes from `--html` or the fetched puzzle
+/// page, the input is downloaded unless it exists or `--offline` is given.
+fn cmd_new(args: &Args) -> Result<ExitCode, String> {
+    let day = args.puzzle_day()?;
+    let offline = args.flag("--offline");
+    let client = Client::new(ClientConfig::default());
+    let page = match args.values.get("--html") {
+        Some(path) => {
+            Some(fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?)
+        }
+        None if offline => None,
+        None => client
+            .puzzle(day, false)
+            .map_err(|e| eprintln!("no example: {}", e))
+            .ok(),
+    };
+    let has_input = input::Source::day(day).path().is_some_and(|p| p.exists());
+    let puzzle_input = if offline || has_input {
+        None
+    } else {
+        client
+            .input(day)
+            .map_err(|e| eprintln!("no input: {}", e))
+            .ok()
+    };
+
+    let created = scaffold::new_day(
+        &scaffold::crate_root(),
+        day,
+        page.as_deref(),
+        puzzle_input.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+    for path in created.files {
+        println!("wrote {}", path.display());
+    }
+    for path in created.skipped {
+        println!("kept {}", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Downloads the input of a day into the inputs directory.
+fn cmd_fetch(args: &Args) -> Result<ExitCode, String> {
+    let day = args.puzzle_day()?;
+    let path = input::Source::day(day)
+        .path()
+        .expect("named inputs have a path");
+    if path.exists() {
+        println!("kept {}", path.display());
+        return Ok(ExitCode::SUCCESS);
+    }
+    let content = Client::new(ClientConfig::default())
+        .input(day)
+        .map_err(|e| e.to_string())?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(&path, content).map_err(|e| e.to_string())?;
+    println!("wrote {}", path.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Submits the answer of one part, unless the submission history already
+/// shows it to be wrong. Correct answers are recorded for `verify`.
+fn cmd_submit(args: &Args) -> Result<ExitCode, String> {
+    let (day, part) = match args.positional.as_slice() {
+        [day, part] => (
+            day.parse::<u8>()
+                .map_err(|_| format!("invalid day {}", day))?,
+            part.parse::<u8>()
+                .ok()
+                .filter(|p| (1..=2).contains(p))
+                .ok_or(format!("invalid part {}", part))?,
+        ),
+        [_] | [] => return Err("missing day or part".to_owned()),
+        [_, _, extra, ..] => return Err(format!("unexpected argument {}", extra)),
+    };
+    let (answer, fingerprint) = match args.values.get("--answer") {
+        Some(answer) => {
+            // Answers are recorded for the input they belong to.
+            let source = match args.values.get("--input") {
+                Some(name) => input::Source::from_arg(day, name),
+                None => input::Source::day(day),
+            };
+            let fingerprint = input::read(&source)
+                .map(|input| answers::fingerprint(&input))
+                .map_err(|e| eprintln!("answer will not be recorded: {}", e))
+                .ok();
+            (answer.clone(), fingerprint)
+        }
+        None => {
+            let day = days::get(day).ok_or(format!("day{} is not registered", day))?;
+            let input = args.input(day)?;
+            let solved = day.solve(&input, Parts::Only(part)).remove(0);
+            println!(
+                "part{} in {:.5} seconds: {}",
+                part,
+                solved.elapsed.as_secs_f32(),
+                solved.answer
+            );
+            (
+                solved.answer.to_string(),
+                Some(answers::fingerprint(&input)),
+            )
+        }
+    };
+
+    let client = Client::new(ClientConfig::default());
+    let mut history = client.history(day).map_err(|e| e.to_string())?;
+    if let Some(reason) = history.refuse(part, &answer) {
+        println!("not submitting: {}", reason);
+        return Ok(ExitCode::FAILURE);
+    }
+    let verdict = client
+        .submit(day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    history.record(part, &answer, &verdict);
+    history
+        .save(&client.history_path(day))
+        .map_err(|e| e.to_string())?;
+    println!("{}: {}", answer, verdict);
+
+    if verdict != Verdict::Correct {
+        return Ok(ExitCode::FAILURE);
+    }
+    if let Some(fingerprint) = fingerprint {
+        let mut answers = Answers::load(day).map_err(|e| e.to_string())?;
+        if answers.get(&fingerprint, part).is_none() {
+            answers.record(&fingerprint, part, &answer);
+            answers.save(day).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn json_row(day: u8, stage: &str, stats: &Stats) -> String {
+    format!(
+        "{{\"day\": {}, \"stage\": \"{}\", \"samples\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}, \"mean_ns\": {}, \"stddev_ns\": {}}}",
+        day,
+        stage,
+        stats.samples,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.stddev.as_nanos(),
+    )
+}
+
+/// Arguments following the command name.
+struct Args {
+    positional: Vec<String>,
+    values: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    /// Options listed in `with_value` consume the next argument, those in `flags` do not.
+    fn parse(args: &[String], with_value: &[&str], flags: &[&str]) -> Result<Self, String> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            values: HashMap::new(),
+            flags: HashSet::new(),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let arg = if arg == "-p" { "--part" } else { arg.as_str() };
+            if with_value.contains(&arg) {
+                let value = args.next().ok_or(format!("{} needs a value", arg))?;
+                parsed.values.insert(arg.to_owned(), value.to_owned());
+            } else if flags.contains(&arg) {
+                parsed.flags.insert(arg.to_owned());
+            } else if arg.starts_with('-') {
+                return Err(format!("unknown option {}", arg));
+            } else {
+                parsed.positional.push(arg.to_owned());
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.values
+            .get(name)
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| format!("invalid value {} for {}", v, name))
+            })
+            .transpose()
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    fn parts(&self) -> Result<Parts, String> {
+        match self.value::<u8>("--part")? {
+            None => Ok(Parts::Both),
+            Some(p @ (1 | 2)) => Ok(Parts::Only(p)),
+            Some(p) => Err(format!("invalid part {}", p)),
+        }
+    }
+
+    /// The input of `day` selected by `--input`.
+    fn input(&self, day: &Day) -> Result<String, String> {
+        let name = self.values.get("--input").map(String::as_str);
+        day.input(name).map_err(|e| {
+            let available = input::names(day.day);
+            if available.is_empty() {
+                e.to_string()
+            } else {
+                format!("{} (available: {})", e, available.join(", "))
+            }
+        })
+    }
+
+    /// The single positional argument as a day of the calendar, registered or not.
+    fn puzzle_day(&self) -> Result<u8, String> {
+        match self.positional.as_slice() {
+            [day] => day
+                .parse::<u8>()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or(format!("invalid day {}", day)),
+            [] => Err("missing day".to_owned()),
+            [_, extra, ..] => Err(format!("unexpected argument {}", extra)),
+        }
+    }
+
+    /// The days selected by the single positional argument, a day number or `all`.
+    fn days(&self) -> Result<Vec<&'static Day>, String> {
+        let days = self.selected_days()?;
+        if let Some(name) = self.values.get("--input") {
+            let named = matches!(
+                input::Source::from_arg(0, name),
+                input::Source::Named { .. }
+            );
+            if days.len() > 1 && !named {
+                return Err(format!(
+                    "--input {} can only be used with a single day",
+                    name
+                ));
+            }
+        }
+        Ok(days)
+    }
+
+    fn selected_days(&self) -> Result<Vec<&'static Day>, String> {
+        match self.positional.as_slice() {
+            [] => Err("missing day".to_owned()),
+            [which] if which == "all" => Ok(days::DAYS.iter().collect()),
+            [which] => {
+                let n: u8 = which
+                    .parse()
+                    .map_err(|_| format!("invalid day {}", which))?;
+                let day = days::get(n).ok_or(format!("day{} is not registered", n))?;
+                Ok(vec![day])
+            }
+            [_, extra, ..] => Err(format!("unexpected argument {}", extra)),
+        }
+    }
+}
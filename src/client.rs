@@ -0,0 +1,381 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::submit::{History, Verdict};
+
+/// Sent with every request, so the Advent of Code maintainers know who to contact.
+pub const USER_AGENT: &str = concat!(
+    "advent-of-code-24/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/wchresta/advent-of-code-24)"
+);
+
+/// Environment variable overriding [`ClientConfig::base_url`].
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Server to talk to, without trailing slash.
+    pub base_url: String,
+    pub year: u16,
+    /// Session cookie; only needed for inputs and submissions.
+    pub session: Option<String>,
+    /// Responses are cached below `<cache_dir>/<year>`.
+    pub cache_dir: PathBuf,
+    /// Minimum time between two requests to the server.
+    pub min_interval: Duration,
+}
+
+impl Default for ClientConfig {
+    /// Talks to adventofcode.com (or `$AOC_BASE_URL`), with the session token
+    /// from `~/.config/aoc/session_token` and the cache in `~/.cache/aoc`.
+    fn default() -> Self {
+        let home = PathBuf::from(std::env::var_os("HOME").unwrap_or_default());
+        let session = fs::read_to_string(home.join(".config/aoc/session_token"))
+            .ok()
+            .map(|token| token.trim().to_owned());
+        let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+            .map_or_else(|| home.join(".cache"), PathBuf::from)
+            .join("aoc");
+        Self {
+            base_url: std::env::var(BASE_URL_ENV)
+                .unwrap_or_else(|_| "https://adventofcode.com".to_owned()),
+            year: 2024,
+            session,
+            cache_dir,
+            min_interval: Duration::from_secs(3),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request needs a session token, but none is configured.
+    NoSession,
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+    },
+    Transport {
+        url: String,
+        message: String,
+    },
+    /// The response to a submission contained no known verdict.
+    NoVerdict,
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSession => write!(f, "no session token in ~/.config/aoc/session_token"),
+            Self::Status { url, status } => write!(f, "{} answered with status {}", url, status),
+            Self::Transport { url, message } => write!(f, "cannot reach {}: {}", url, message),
+            Self::NoVerdict => write!(f, "the response contained no verdict"),
+            Self::Io(e) => write!(f, "cache: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Fetches puzzle pages and inputs, caching them on disk and spacing out
+/// requests by at least [`ClientConfig::min_interval`].
+pub struct Client {
+    config: ClientConfig,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self { config, agent }
+    }
+
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// The puzzle input of `day`. Inputs never change, so they are only fetched once.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let cache = self.cache_path(day, "input");
+        if let Ok(content) = fs::read_to_string(&cache) {
+            return Ok(content);
+        }
+        let content = self.get(&format!("{}/day/{}/input", self.year_url(), day), true)?;
+        store(&cache, &content)?;
+        Ok(content)
+    }
+
+    /// The puzzle page of `day`, from the cache unless `refresh` is set.
+    /// Refresh after solving part 1 to see part 2.
+    pub fn puzzle(&self, day: u8, refresh: bool) -> Result<String, ClientError> {
+        let cache = self.cache_path(day, "puzzle.html");
+        if !refresh {
+            if let Ok(content) = fs::read_to_string(&cache) {
+                return Ok(content);
+            }
+        }
+        let session = self.config.session.is_some();
+        let content = self.get(&format!("{}/day/{}", self.year_url(), day), session)?;
+        store(&cache, &content)?;
+        Ok(content)
+    }
+
+    /// Submits `answer` for `part` of `day`. Only use this directly when
+    /// checking [`Client::history`] first is not wanted.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, ClientError> {
+        let url = format!("{}/day/{}/answer", self.year_url(), day);
+        let request = self.request("POST", &url, true)?;
+        self.wait_turn()?;
+        let level = part.to_string();
+        let html = read_response(
+            &url,
+            request.send_form(&[("level", &level), ("answer", answer)]),
+        )?;
+        Verdict::parse(&html).ok_or(ClientError::NoVerdict)
+    }
+
+    /// Where the answers submitted for `day` and their verdicts are kept.
+    pub fn history_path(&self, day: u8) -> PathBuf {
+        self.cache_path(day, "submissions")
+    }
+
+    pub fn history(&self, day: u8) -> Result<History, ClientError> {
+        Ok(History::load(&self.history_path(day))?)
+    }
+
+    fn year_url(&self) -> String {
+        format!("{}/{}", self.config.base_url, self.config.year)
+    }
+
+    fn cache_path(&self, day: u8, file: &str) -> PathBuf {
+        self.config
+            .cache_dir
+            .join(self.config.year.to_string())
+            .join(format!("day{}", day))
+            .join(file)
+    }
+
+    /// Sleeps until the next request is allowed. The time of the last request
+    /// is kept in the cache, so separate runs are spaced out as well.
+    fn wait_turn(&self) -> io::Result<()> {
+        let stamp = self.config.cache_dir.join("last_request");
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last) = last {
+            let next = last + self.config.min_interval;
+            if let Ok(wait) = next.duration_since(SystemTime::now()) {
+                // A stamp from the future must not block requests for long.
+                thread::sleep(wait.min(self.config.min_interval));
+            }
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        store(&stamp, &now.as_millis().to_string())
+    }
+
+    fn request(
+        &self,
+        method: &str,
+        url: &str,
+        session: bool,
+    ) -> Result<ureq::Request, ClientError> {
+        let mut request = self.agent.request(method, url);
+        if session {
+            let token = self.config.session.as_ref().ok_or(ClientError::NoSession)?;
+            request = request.set("Cookie", &format!("session={}", token));
+        }
+        Ok(request)
+    }
+
+    fn get(&self, url: &str, session: bool) -> Result<String, ClientError> {
+        let request = self.request("GET", url, session)?;
+        self.wait_turn()?;
+        read_response(url, request.call())
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(ClientError::Io),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status {
+            url: url.to_owned(),
+            status,
+        }),
+        Err(e) => Err(ClientError::Transport {
+            url: url.to_owned(),
+            message: e.to_string(),
+        }),
+    }
+}
+
+fn store(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+}
+
+/// A stand-in server answering each connection with the next of `responses`
+/// as `(status, body)`. Yields the raw requests it received once all were served.
+#[cfg(test)]
+pub(crate) fn serve(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            requests.push(request);
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+#[cfg(test)]
+pub(crate) fn test_config(base_url: String, name: &str) -> ClientConfig {
+    let cache_dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&cache_dir);
+    ClientConfig {
+        base_url,
+        year: 2024,
+        session: Some("53cr3t".to_owned()),
+        cache_dir,
+        min_interval: Duration::ZERO,
+    }
+}
+
+#[test]
+fn test_input_is_cached() {
+    let (base_url, server) = serve(vec![(200, "3   4\n4   3\n")]);
+    let client = Client::new(test_config(base_url, "input"));
+    assert_eq!(client.input(1).unwrap(), "3   4\n4   3\n");
+    assert_eq!(client.input(1).unwrap(), "3   4\n4   3\n");
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("Cookie: session=53cr3t\r\n"));
+    assert!(requests[0].contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    fs::remove_dir_all(&client.config().cache_dir).unwrap();
+}
+
+#[test]
+fn test_puzzle_refresh_and_errors() {
+    let (base_url, server) = serve(vec![(200, "part one"), (200, "part two"), (404, "not yet")]);
+    let mut config = test_config(base_url, "puzzle");
+    config.min_interval = Duration::from_millis(50);
+    let client = Client::new(config);
+    let start = std::time::Instant::now();
+    assert_eq!(client.puzzle(2, false).unwrap(), "part one");
+    assert_eq!(client.puzzle(2, false).unwrap(), "part one");
+    assert_eq!(client.puzzle(2, true).unwrap(), "part two");
+    assert!(matches!(
+        client.input(3),
+        Err(ClientError::Status { status: 404, .. })
+    ));
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(server.join().unwrap().len(), 3);
+
+    let mut config = client.config().clone();
+    config.session = None;
+    assert!(matches!(
+        Client::new(config).input(4),
+        Err(ClientError::NoSession)
+    ));
+    fs::remove_dir_all(&client.config().cache_dir).unwrap();
+}
+
+#[test]
+fn test_interval_across_clients() {
+    let (base_url, server) = serve(vec![(200, "1"), (200, "2")]);
+    let mut config = test_config(base_url, "interval");
+    config.min_interval = Duration::from_millis(100);
+    let start = std::time::Instant::now();
+    assert_eq!(Client::new(config.clone()).input(1).unwrap(), "1");
+    // A new client, as in the next run of aoc, waits for the one before.
+    assert_eq!(Client::new(config.clone()).input(2).unwrap(), "2");
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert_eq!(server.join().unwrap().len(), 2);
+    fs::remove_dir_all(&config.cache_dir).unwrap();
+}
+
+#[test]
+fn test_submit() {
+    let (base_url, server) = serve(vec![
+        (
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        ),
+        (200, "<article><p>That's the right answer!</p></article>"),
+        (200, "<html>Login</html>"),
+    ]);
+    let client = Client::new(test_config(base_url, "submit"));
+    assert_eq!(client.submit(5, 2, "41").unwrap(), Verdict::TooLow);
+    assert_eq!(client.submit(5, 2, "co,de").unwrap(), Verdict::Correct);
+    assert!(matches!(
+        client.submit(5, 2, "43"),
+        Err(ClientError::NoVerdict)
+    ));
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("POST /2024/day/5/answer HTTP/1.1\r\n"));
+    assert!(requests[0].contains("Cookie: session=53cr3t\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=41"));
+    assert!(requests[1].ends_with("level=2&answer=co%2Cde"));
+    assert!(client.history(5).unwrap().refuse(2, "41").is_none());
+}
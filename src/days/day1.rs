@@ -0,0 +1,54 @@
+use std::{collections::HashMap, iter::zip};
+
+use crate::{input, Solution};
+
+type S = Vec<[i64; 2]>;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = S;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> S {
+        input::as_as(s)
+    }
+
+    fn part1(content: &S) -> i64 {
+        let mut lefts = Vec::new();
+        let mut rights = Vec::new();
+        for [left, right] in content {
+            lefts.push(left);
+            rights.push(right);
+        }
+
+        lefts.sort();
+        rights.sort();
+
+        zip(lefts, rights).map(|(x, y)| (y - x).abs()).sum::<i64>()
+    }
+
+    fn part2(content: &S) -> i64 {
+        let mut right_counts = HashMap::<i64, i64>::new();
+        for [_, right] in content {
+            match right_counts.get_mut(right) {
+                Some(x) => {
+                    *x += 1;
+                }
+                None => {
+                    right_counts.insert(*right, 1);
+                }
+            }
+        }
+
+        let mut score = 0;
+        for [left, _] in content {
+            if let Some(count) = right_counts.get(left) {
+                score += left * count
+            }
+        }
+
+        score
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    input,
+    square::{flood_fill, straight_neighbours, Grid, Pos, PosIter, STRAIGHT},
+    Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = S;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(s: &str) -> S {
+        Grid::from_rows(input::as_digit_square(s))
+    }
+
+    fn part1(bx: &S) -> u64 {
+        bx.pos_iter()
+            .filter(|(_, h)| *h == 0)
+            .map(|(pos, _)| {
+                flood_fill(bx, pos, &STRAIGHT, |from, to| from + 1 == *to)
+                    .into_iter()
+                    .filter(|p| bx[*p] == 9)
+                    .count() as u64
+            })
+            .sum()
+    }
+
+    fn part2(bx: &S) -> u64 {
+        let topo = Topo::new(bx.clone());
+        let mut scores: Vec<u64> = Vec::new();
+        for (pos, h) in bx.pos_iter() {
+            if h != 0 {
+                continue;
+            }
+
+            let paths = topo.uphill_paths(pos);
+            scores.push(paths.len() as u64)
+        }
+        scores.iter().sum()
+    }
+}
+
+type S = Grid<u8>;
+
+struct Topo {
+    heights: Grid<u8>,
+}
+
+impl Topo {
+    pub fn new(heights: Grid<u8>) -> Self {
+        Topo { heights }
+    }
+
+    pub fn uphill_paths(&self, pos: Pos) -> Vec<Vec<Pos>> {
+        if self.get(pos).unwrap() == 9 {
+            return Vec::from([Vec::from([pos])]);
+        }
+
+        self.uphills(pos)
+            .iter()
+            .filter_map(|p| {
+                let tail = self.uphill_paths(*p);
+                if tail.is_empty() {
+                    None
+                } else {
+                    Some(
+                        tail.iter()
+                            .map(|t| {
+                                let mut t = t.to_vec();
+                                t.insert(0, pos);
+                                t
+                            })
+                            .collect::<Vec<Vec<Pos>>>(),
+                    )
+                }
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn uphills(&self, pos: Pos) -> Vec<Pos> {
+        let h = self.heights[pos];
+        straight_neighbours(&self.heights, pos, |_, w| h + 1 == *w)
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<u8> {
+        self.heights.get(pos).copied()
+    }
+}
@@ -1,23 +1,33 @@
 use std::collections::HashMap;
 
-use advent_of_code_24::{digits, input, solve, split_digits};
+use crate::{digits, input, split_digits, Solution};
 
 #[allow(dead_code)]
 const TEST_INPUT: &str = "125 17";
 
-fn main() {
-    solve("day11", input::single_line_vec, part1, part2);
-}
+pub struct Day11;
 
-fn part1(stones: &Vec<u64>) -> u64 {
-    run_stones(25, stones)
-}
+impl Solution for Day11 {
+    type Input = S;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(s: &str) -> S {
+        input::single_line_vec(s)
+    }
 
-fn part2(stones: &Vec<u64>) -> u64 {
-    run_stones(75, stones)
+    fn part1(stones: &S) -> u64 {
+        run_stones(25, stones)
+    }
+
+    fn part2(stones: &S) -> u64 {
+        run_stones(75, stones)
+    }
 }
 
-fn run_stones(steps: u64, stones: &Vec<u64>) -> u64 {
+type S = Vec<u64>;
+
+fn run_stones(steps: u64, stones: &[u64]) -> u64 {
     Runner::new().run_stones(steps, stones)
 }
 
@@ -33,7 +43,7 @@ impl Runner {
         }
     }
 
-    pub fn run_stones(&mut self, steps: u64, stones: &Vec<u64>) -> u64 {
+    pub fn run_stones(&mut self, steps: u64, stones: &[u64]) -> u64 {
         stones.iter().map(|s| self.run_stone(steps, *s)).sum()
     }
 
@@ -82,7 +92,7 @@ impl Runner {
     pub fn stone_step(stone: u64) -> Vec<u64> {
         match stone {
             0 => Vec::from([1]),
-            x if digits(x) % 2 == 0 => {
+            x if digits(x).is_multiple_of(2) => {
                 let (a, b) = split_digits(x);
                 Vec::from([a, b])
             }
@@ -93,9 +103,7 @@ impl Runner {
 
 #[test]
 fn test_part1() {
-    advent_of_code_24::test1("125 17", 13, input::single_line_vec, |s| run_stones(5, s));
-    advent_of_code_24::test1("125 17", 22, input::single_line_vec, |s| run_stones(6, s));
-    advent_of_code_24::test1(TEST_INPUT, 55312, input::single_line_vec, |s| {
-        run_stones(25, s)
-    });
+    assert_eq!(run_stones(5, &Day11::parse("125 17")), 13);
+    assert_eq!(run_stones(6, &Day11::parse("125 17")), 22);
+    assert_eq!(run_stones(25, &Day11::parse(TEST_INPUT)), 55312);
 }
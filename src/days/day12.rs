@@ -0,0 +1,34 @@
+use crate::{
+    input,
+    square::{self, Grid, Region, STRAIGHT},
+    Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(s: &str) -> Grid<char> {
+        input::as_grid(s)
+    }
+
+    fn part1(garden: &Grid<char>) -> u64 {
+        regions(garden)
+            .map(|r| (r.area() * r.perimeter()) as u64)
+            .sum()
+    }
+
+    fn part2(garden: &Grid<char>) -> u64 {
+        regions(garden).map(|r| (r.area() * r.sides()) as u64).sum()
+    }
+}
+
+fn regions(garden: &Grid<char>) -> impl Iterator<Item = Region> {
+    square::components(garden, &STRAIGHT, |a, b| a == b)
+        .cells
+        .into_iter()
+        .map(Region::new)
+}
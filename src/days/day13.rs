@@ -0,0 +1,62 @@
+extern crate nalgebra as na;
+
+use crate::{input, Solution};
+use na::linalg::{self};
+use na::{Matrix2, Vector2};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Puzzle>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(content: &str) -> Vec<Puzzle> {
+        input::scan_all(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+            content,
+        )
+        .into_iter()
+        .map(|[ax, ay, bx, by, px, py]: [f64; 6]| Puzzle {
+            ab: Matrix2::from([[ax, ay], [bx, by]]),
+            p: Vector2::from([px, py]),
+        })
+        .collect()
+    }
+
+    fn part1(puzzles: &Vec<Puzzle>) -> i64 {
+        let mut score = 0;
+        for p in puzzles {
+            if let Some(soln) = linalg::LU::new(p.ab).solve(&p.p) {
+                let (xf, yf) = (soln.get(0).unwrap(), soln.get(1).unwrap());
+                let (x, y) = (xf.round(), yf.round());
+                if (x - xf).abs() < 0.00001 && (y - yf).abs() < 0.00001 {
+                    score += 3 * x as i64 + y as i64
+                }
+            }
+        }
+        score
+    }
+
+    fn part2(puzzles: &Vec<Puzzle>) -> i64 {
+        let mut score = 0;
+        for p in puzzles {
+            if let Some(soln) = linalg::LU::new(p.ab).solve(&p.p.add_scalar(PART2_MUL)) {
+                let (xf, yf) = (soln.get(0).unwrap(), soln.get(1).unwrap());
+                let (x, y) = (xf.round(), yf.round());
+                if (x - xf).abs() < 0.001 && (y - yf).abs() < 0.001 {
+                    score += 3 * x as i64 + y as i64
+                }
+            }
+        }
+        score
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    ab: Matrix2<f64>,
+    p: Vector2<f64>,
+}
+
+const PART2_MUL: f64 = 10000000000000f64;
@@ -0,0 +1,156 @@
+extern crate nalgebra as na;
+
+use std::collections::HashSet;
+
+use crate::input;
+use crate::render;
+use crate::square::{Pos, Topology, Vec2};
+use crate::Solution;
+use na::Vector4;
+use rayon::prelude::*;
+
+type V = Vec2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<(V, V)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(inp: &str) -> Vec<(V, V)> {
+        input::scan_all("p={},{} v={},{}", inp)
+            .into_iter()
+            .map(|[px, py, vx, vy]: [isize; 4]| (Pos::new(px, py), Pos::new(vx, vy)))
+            .collect()
+    }
+
+    fn part1(puzzles: &Vec<(V, V)>) -> i64 {
+        simulate1(puzzles, 101, 103)
+    }
+
+    fn part2(puzzles: &Vec<(V, V)>) -> i64 {
+        simulate2(puzzles, 101, 103)
+    }
+
+    /// The robots at the time `simulate2` finds the tree.
+    fn render(puzzles: &Vec<(V, V)>) -> Option<String> {
+        let floor = Topology::torus(101, 103);
+        let mut puz = puzzles.to_owned();
+        for _ in 0..simulate2(puzzles, 101, 103) {
+            step(&mut puz, &floor);
+        }
+        Some(display(&puz, 101, 103))
+    }
+}
+
+fn simulate1(puzzles: &Vec<(V, V)>, width: isize, height: isize) -> i64 {
+    let mid_x = (width - 1) / 2; // 50 for width=101
+    let mid_y = (height - 1) / 2; // 51 for height=103
+    let floor = Topology::torus(width as usize, height as usize);
+    let quadrants = puzzles
+        .par_iter()
+        .map(|(pos, vel)| {
+            let end = floor.step(*pos, *vel * 100).unwrap();
+            let mut quadrant = 0;
+            if end.x == mid_x || end.y == mid_y {
+                return Vector4::zeros();
+            }
+            if end.x > mid_x {
+                quadrant += 1;
+            }
+            if end.y > mid_y {
+                quadrant += 2;
+            }
+
+            match quadrant {
+                0 => Vector4::new(1, 0, 0, 0),
+                1 => Vector4::new(0, 1, 0, 0),
+                2 => Vector4::new(0, 0, 1, 0),
+                3 => Vector4::new(0, 0, 0, 1),
+                _ => panic!("unknown quadrant"),
+            }
+        })
+        .reduce(Vector4::zeros, |v, w| v + w);
+    quadrants.product()
+}
+
+#[allow(dead_code)]
+const TEST_INPUT: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+#[test]
+fn test_part1() {
+    assert_eq!(simulate1(&Day14::parse(TEST_INPUT), 11, 7), 12);
+}
+
+fn simulate2(puzzles: &Vec<(V, V)>, width: isize, height: isize) -> i64 {
+    let floor = Topology::torus(width as usize, height as usize);
+    let mut puz = puzzles.to_owned();
+    for t in 1..50_000 {
+        step(&mut puz, &floor);
+
+        let pos: HashSet<Pos> = HashSet::from_iter(puz.iter().map(|(p, _)| *p));
+        for x in 0..width {
+            let mut in_a_row = 0;
+            for y in 0..height {
+                if pos.contains(&Pos::new(x, y)) {
+                    in_a_row += 1;
+                } else {
+                    in_a_row = 0;
+                }
+
+                if in_a_row > 10 {
+                    return t;
+                }
+            }
+        }
+    }
+    // Not found :(
+    0
+}
+
+/// The robots per tile as drawn in the puzzle.
+fn display(puzzles: &[(V, V)], width: usize, height: usize) -> String {
+    let robots = render::plot(width, height, puzzles.iter().map(|(p, _)| *p));
+    render::text(&robots, |n| match n {
+        0 => '.',
+        n => char::from_digit(*n as u32, 36).unwrap_or('#'),
+    })
+}
+
+fn step(puzzles: &mut Vec<(V, V)>, floor: &Topology) {
+    puzzles.par_iter_mut().for_each(|(pos, vel)| {
+        *pos = floor.step(*pos, *vel).unwrap();
+    });
+}
+#[test]
+#[ignore = "the example robots never line up into a tree"]
+fn test_part2() {
+    assert_eq!(simulate2(&Day14::parse(TEST_INPUT), 11, 7), 12);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        display(&Day14::parse(TEST_INPUT), 11, 7),
+        "1.12.......
+...........
+...........
+......11.11
+1.1........
+.........1.
+.......1...
+"
+    );
+}
@@ -0,0 +1,156 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    input::{self, Marker},
+    square::{Dir4, Grid, Pos, PosIter},
+    Solution,
+};
+
+type M = Grid<char>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (M, Pos, Vec<char>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(content: &str) -> (M, Pos, Vec<char>) {
+        let [m, vs] = input::sections(content, ["map", "moves"]);
+        let (m, found) = m.parse(|s| input::try_as_grid_with_markers(s, &[Marker::one('@', '.')]));
+        (m, found.pos('@'), vs.text.chars().collect())
+    }
+
+    fn part1((m, start, vs): &(M, Pos, Vec<char>)) -> i64 {
+        let mut m = m.to_owned();
+        let mut pos = *start;
+        for v in vs {
+            pos = step1(&mut m, pos, *v);
+        }
+        // println!("=======\n{}", m.rows().map(|line| line.iter().collect::<String>()).collect::<Vec<String>>().join("\n"));
+        gps_sum(&m, 'O')
+    }
+
+    fn part2((m, start, vs): &(M, Pos, Vec<char>)) -> i64 {
+        let mut m: M = Grid::from_rows(m.rows().map(|line| {
+            line.iter()
+                .flat_map(|x| match *x {
+                    '#' => vec!['#', '#'],
+                    'O' => vec!['[', ']'],
+                    '.' => vec!['.', '.'],
+                    _ => vec![],
+                })
+                .collect()
+        }));
+
+        let mut pos = Pos::new(start.x * 2, start.y);
+
+        for v in vs {
+            /*
+            println!(
+                "======= :: {}\n{}",
+                v,
+                m.rows()
+                    .map(|line| line.iter().collect::<String>())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+            */
+            pos = step2(&mut m, pos, *v);
+        }
+        // println!("=======\n{}", m.rows().map(|line| line.iter().collect::<String>()).collect::<Vec<String>>().join("\n"));
+        gps_sum(&m, '[')
+    }
+}
+
+/// Sum of the GPS coordinates of all `boxes`.
+fn gps_sum(m: &M, boxes: char) -> i64 {
+    m.pos_iter()
+        .filter(|(_, c)| *c == boxes)
+        .map(|(p, _)| (p.x + 100 * p.y) as i64)
+        .sum()
+}
+
+fn get(m: &M, p: Pos) -> char {
+    m[p]
+}
+
+fn set(m: &mut M, p: Pos, c: char) {
+    m[p] = c;
+}
+
+fn step1(m: &mut M, pos: Pos, v: char) -> Pos {
+    let Ok(dir) = Dir4::try_from(v) else {
+        return pos;
+    };
+    let move_to = pos + dir;
+    let mut look = move_to;
+    loop {
+        match get(m, look) {
+            'O' => {
+                look += dir;
+                continue;
+            }
+            '#' => {
+                return pos;
+            }
+            _ => break,
+        }
+    }
+    if get(m, move_to) == 'O' {
+        // We need to move the stone first
+        set(m, look, 'O');
+    }
+    // We move
+    set(m, move_to, '.');
+    move_to
+}
+
+fn step2(m: &mut M, pos: Pos, v: char) -> Pos {
+    let Ok(dir) = Dir4::try_from(v) else {
+        return pos;
+    };
+
+    let mut needs_move = Vec::from([pos]);
+    let mut needs_check = VecDeque::from([HashSet::from([pos])]);
+    while let Some(layer) = needs_check.pop_front() {
+        // layer contains elements we know can and must be moved.
+        // We also know they are already in needs_move.
+        let mut new_layer = HashSet::new();
+        for p in layer {
+            let p_new = p + dir;
+            match get(m, p_new) {
+                '#' => return pos,
+                '[' => {
+                    new_layer.insert(p_new);
+                    if !dir.is_horizontal() {
+                        // Because p_new is [, we know p_new + (1,0) must be ']'
+                        new_layer.insert(p_new + Dir4::East);
+                    }
+                }
+                ']' => {
+                    new_layer.insert(p_new);
+                    if !dir.is_horizontal() {
+                        // Because p_new is ], we know p_new + (-1,0) must be '['
+                        new_layer.insert(p_new + Dir4::West);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if !new_layer.is_empty() {
+            // Ensure new_layer is moved when we move things.
+            needs_check.push_back(new_layer.clone());
+            needs_move.append(&mut Vec::from_iter(new_layer));
+        }
+    }
+
+    // All moves would be successful, so perform them.
+    needs_move.reverse();
+    for p in needs_move {
+        set(m, p + dir, get(m, p));
+        set(m, p, '.');
+    }
+
+    pos + dir
+}
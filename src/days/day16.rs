@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::input::{self, Marker};
+use crate::search;
+use crate::square::Grid;
+use crate::square::PosGet;
+use crate::square::{Dir4, Pos};
+use crate::Solution;
+
+pub struct Day16;
+
+input::grid_cells! {
+    pub enum Tile {
+        Wall = '#',
+        Floor = '.',
+    }
+}
+
+impl Solution for Day16 {
+    type Input = State;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> State {
+        let markers = [Marker::one('S', Tile::Floor), Marker::one('E', Tile::Floor)];
+        let (rect, found) = input::as_grid_with_markers(s, &markers);
+        State {
+            rect,
+            start: found.pos('S'),
+            exit: found.pos('E'),
+        }
+    }
+
+    fn part1(state: &State) -> i64 {
+        search::dijkstra(
+            (state.start, Dir4::East),
+            |s| state.moves(s),
+            |(pos, _)| *pos == state.exit,
+        )
+        .goal_cost()
+        .unwrap_or(0)
+    }
+
+    fn part2(state: &State) -> i64 {
+        let search = search::dijkstra_all((state.start, Dir4::East), |s| state.moves(s), |_| false);
+
+        // The exit may be reached facing different directions at the same cost.
+        let exits = Dir4::ALL.map(|d| (state.exit, d));
+        let Some(best) = exits.iter().filter_map(|e| search.distance(e)).min() else {
+            return 0;
+        };
+        exits
+            .iter()
+            .filter(|e| search.distance(e) == Some(best))
+            .flat_map(|e| search.states_on_paths_to(e))
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<_>>()
+            .len() as i64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    rect: Grid<Tile>,
+    start: Pos,
+    exit: Pos,
+}
+
+impl State {
+    /// Moving forward costs 1, turning and moving 1001.
+    fn moves(&self, &(pos, dir): &(Pos, Dir4)) -> Vec<(i64, (Pos, Dir4))> {
+        [
+            (1i64, dir),
+            (1001, dir.turn_left()),
+            (1001, dir.turn_right()),
+        ]
+        .into_iter()
+        .map(|(cost, d)| (cost, (pos + d, d)))
+        .filter(|(_, (p, _))| self.rect.pos_get(*p) != Tile::Wall)
+        .collect()
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use crate::search;
+use crate::square::{Pos, STRAIGHT};
+use crate::Solution;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = S;
+    type Part1 = i64;
+    type Part2 = Pos;
+
+    fn parse(s: &str) -> S {
+        s.lines()
+            .map(|l| {
+                let (x, y) = l.split_once(',').unwrap();
+                Pos::new(x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn part1(s: &S) -> i64 {
+        drop_and_solve_maze(s, 1024, 70)
+    }
+
+    fn part2(s: &S) -> Pos {
+        find_failure(s, 1024, 70)
+    }
+}
+
+type S = Vec<Pos>;
+
+fn drop_and_solve_maze(s: &S, take: usize, max: isize) -> i64 {
+    let walls: HashSet<Pos> = HashSet::from_iter(s.clone().into_iter().take(take));
+    search::bfs(
+        Pos::ZERO,
+        |pos| {
+            neighbours(max, *pos)
+                .into_iter()
+                .filter(|n| !walls.contains(n))
+        },
+        |pos| *pos == Pos::new(max, max),
+    )
+    .goal_cost()
+    .map_or(0, |cost| cost as i64)
+}
+
+fn neighbours(max: isize, pos: Pos) -> Vec<Pos> {
+    STRAIGHT
+        .iter()
+        .map(|d| pos + *d)
+        .filter(|p| p.x >= 0 && p.y >= 0 && p.x <= max && p.y <= max)
+        .collect()
+}
+
+fn find_failure(s: &S, min_fall: usize, max: isize) -> Pos {
+    // Binary search
+    // a b are number of elements that are to be used.
+    let mut a = min_fall;
+    let mut b = s.len();
+
+    while a + 1 < b {
+        let c = (a + b) / 2;
+        if drop_and_solve_maze(s, c, max) > 0 {
+            // There is a solution
+            a = c;
+        } else {
+            // There is no solution
+            b = c - 1;
+        }
+    }
+    // a has a path, b does not have a path.
+    // So the b'ts element (index b-1) does not allow any path.
+    s[b - 1]
+}
+
+#[allow(dead_code)]
+const TEST_INPUT: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+#[test]
+fn test_part1() {
+    assert_eq!(drop_and_solve_maze(&Day18::parse(TEST_INPUT), 12, 6), 22);
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        find_failure(&Day18::parse(TEST_INPUT), 1, 6),
+        Pos::new(6, 1)
+    );
+}
@@ -0,0 +1,76 @@
+use crate::{input, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = P;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> P {
+        let [stripes, towels] = input::sections(s, ["patterns", "towels"]);
+        let mut stripes: Vec<String> = stripes.parse(|s| input::try_as_separated(s, ", ")).concat();
+        let towels = input::as_lines(towels.text);
+        // It's better for search algorithms to have chunky things first.
+        stripes.sort_by_key(|s| s.len());
+        stripes.reverse();
+        P { stripes, towels }
+    }
+
+    fn part1(p: &P) -> i64 {
+        p.towels
+            .iter()
+            .filter(|t| can_be_made(t, &p.stripes))
+            .count() as i64
+    }
+
+    fn part2(p: &P) -> i64 {
+        let mut total_score = 0;
+        for towel in &p.towels {
+            // Scores contains the ways of combinations
+            // the last k characters of towel can be arranged.
+            // So scores[0] is 1
+            let mut scores = Vec::with_capacity(towel.len() + 1);
+            scores.push(1);
+            for k in 1..towel.len() + 1 {
+                let end = &towel[towel.len() - k..];
+                let mut k_score = 0;
+                for s in &p.stripes {
+                    if end.starts_with(s) {
+                        let rest_score = scores[k - s.len()];
+                        k_score += rest_score;
+                    }
+                }
+                scores.push(k_score);
+            }
+            total_score += scores.last().unwrap();
+        }
+        total_score
+    }
+}
+
+pub struct P {
+    stripes: Vec<String>,
+    towels: Vec<String>,
+}
+
+fn can_be_made(towel: &str, ps: &Vec<String>) -> bool {
+    let mut stack = Vec::from([towel]);
+    while let Some(t) = stack.pop() {
+        for p in ps {
+            if !t.starts_with(p) {
+                continue;
+            }
+            let left_piece = &t[p.len()..];
+            if left_piece.is_empty() {
+                return true;
+            }
+            stack.push(left_piece);
+        }
+    }
+    false
+}
+
+// We have singles for w,u,r,g
+// That means only b (black) are non-singles.
+// We can probably use b's to split our problems easily.
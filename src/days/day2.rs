@@ -0,0 +1,51 @@
+use crate::{input, Solution};
+use std::iter::zip;
+
+type S = Vec<Vec<i32>>;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = S;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(s: &str) -> S {
+        input::as_vs(s)
+    }
+
+    fn part1(content: &S) -> usize {
+        let diffs: Vec<Vec<i32>> = content
+            .iter()
+            .map(|r: &Vec<i32>| {
+                zip(r.iter(), r.iter().skip(1))
+                    .map(|(x, y)| y - x)
+                    .collect()
+            })
+            .collect();
+        diffs.iter().filter(|r| is_safe(r)).count()
+    }
+
+    fn part2(content: &S) -> usize {
+        let mut count = 0;
+        for report in content {
+            for i in 0..report.len() {
+                let cut_report = [&report[..i], &report[i + 1..]].concat();
+                let diffs: Vec<i32> = zip(cut_report.iter(), cut_report.iter().skip(1))
+                    .map(|(x, y)| y - x)
+                    .collect();
+                if is_safe(&diffs) {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+        count
+    }
+}
+
+fn is_safe(r: &[i32]) -> bool {
+    let monotone = r.iter().all(|x| *x > 0) || r.iter().all(|x| *x < 0);
+    let bounded = r.iter().all(|x| x.abs() <= 3);
+    monotone && bounded
+}
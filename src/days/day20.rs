@@ -1,60 +1,65 @@
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::HashMap;
 
-use advent_of_code_24::{
-    input,
-    square::{pos_add, straight_neighbours, Counter, Pos, PosFind, M},
+use crate::{
+    input::{self, Marker},
+    search,
+    square::{straight_neighbours, Counter, Grid, Pos},
+    Solution,
 };
 use itertools::Itertools;
 
-fn main() {
-    advent_of_code_24::solve("day20", parse, part1, part2);
-}
+pub struct Day20;
 
-#[derive(Debug, Clone)]
-struct S {
-    maze: M<char>,
-    start_pos: Pos,
-    end_pos: Pos,
+input::grid_cells! {
+    pub enum Tile {
+        Wall = '#',
+        Track = '.',
+    }
 }
 
-impl S {
-    fn find_distances(&self, start: Pos) -> HashMap<Pos, isize> {
-        let mut heap = BinaryHeap::new();
-        let mut distances = HashMap::new();
-
-        distances.insert(start, 0);
-        heap.push((0, start));
+impl Solution for Day20 {
+    type Input = S;
+    type Part1 = isize;
+    type Part2 = isize;
 
-        while let Some((score, pos)) = heap.pop() {
-            for (npos, _) in straight_neighbours(&self.maze, pos, |_, t| *t != '#') {
-                if distances.contains_key(&npos) {
-                    continue;
-                }
-                distances.insert(npos, -score + 1);
-                heap.push(((score - 1), npos))
-            }
+    fn parse(s: &str) -> S {
+        let markers = [Marker::one('S', Tile::Track), Marker::one('E', Tile::Track)];
+        let (maze, found) = input::as_grid_with_markers(s, &markers);
+        S {
+            maze,
+            start_pos: found.pos('S'),
+            end_pos: found.pos('E'),
         }
-        distances
     }
-}
 
-fn parse(s: &str) -> S {
-    let maze = input::as_vecvec(s);
-    let start_pos = maze.pos_find('S').unwrap();
-    let end_pos = maze.pos_find('E').unwrap();
-    S {
-        maze,
-        start_pos,
-        end_pos,
+    fn part1(s: &S) -> isize {
+        saving_min(s, 2, 100).iter().map(|(_, s)| s).sum()
+    }
+
+    fn part2(s: &S) -> isize {
+        saving_min(s, 20, 100).iter().map(|(_, s)| s).sum()
     }
 }
 
-fn part1(s: &S) -> isize {
-    saving_min(s, 2, 100).iter().map(|(_, s)| s).sum()
+#[derive(Debug, Clone)]
+pub struct S {
+    maze: Grid<Tile>,
+    start_pos: Pos,
+    end_pos: Pos,
 }
 
-fn part2(s: &S) -> isize {
-    saving_min(s, 20, 100).iter().map(|(_, s)| s).sum()
+impl S {
+    fn find_distances(&self, start: Pos) -> HashMap<Pos, isize> {
+        search::bfs(
+            start,
+            |pos| straight_neighbours(&self.maze, *pos, |_, t| *t != Tile::Wall).map(|(n, _)| n),
+            |_| false,
+        )
+        .into_distances()
+        .into_iter()
+        .map(|(pos, dist)| (pos, dist as isize))
+        .collect()
+    }
 }
 
 fn savings(s: &S, dist: isize) -> Vec<(isize, isize)> {
@@ -66,7 +71,7 @@ fn savings(s: &S, dist: isize) -> Vec<(isize, isize)> {
     for (pos, cost) in start_distances.iter() {
         for dx in -dist..dist + 1 {
             for dy in -dist + dx.abs()..dist - dx.abs() + 1 {
-                if let Some(rest_cost) = end_distances.get(&pos_add(*pos, (dx, dy))) {
+                if let Some(rest_cost) = end_distances.get(&(*pos + Pos::new(dx, dy))) {
                     let total_cost = cost + rest_cost + dx.abs() + dy.abs();
                     let saved = baseline - total_cost;
                     if saved > 0 {
@@ -109,8 +114,8 @@ const TEST_INPUT: &str = "###############
 
 #[test]
 fn test_part1() {
-    advent_of_code_24::test1(
-        TEST_INPUT,
+    assert_eq!(
+        savings(&Day20::parse(TEST_INPUT), 2),
         vec![
             (2, 14),
             (4, 14),
@@ -123,16 +128,14 @@ fn test_part1() {
             (38, 1),
             (40, 1),
             (64, 1),
-        ],
-        parse,
-        |s| savings(s, 2),
+        ]
     );
 }
 
 #[test]
 fn test_part2() {
-    advent_of_code_24::test1(
-        TEST_INPUT,
+    assert_eq!(
+        saving_min(&Day20::parse(TEST_INPUT), 20, 50),
         vec![
             (50, 32),
             (52, 31),
@@ -148,8 +151,6 @@ fn test_part2() {
             (72, 22),
             (74, 4),
             (76, 3),
-        ],
-        parse,
-        |s| saving_min(s, 20, 50),
+        ]
     );
 }
@@ -1,15 +1,28 @@
 extern crate lazy_static;
 
-use advent_of_code_24::{
-    input,
-    square::{pos_sub, Pos},
-};
+use crate::{input, square::Pos, Solution};
 use lazy_static::lazy_static;
 use memoize::memoize;
 use std::collections::HashMap;
 
-fn main() {
-    advent_of_code_24::solve("day21", input::as_lines, part1, part2);
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = S;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(s: &str) -> S {
+        input::as_lines(s)
+    }
+
+    fn part1(s: &S) -> usize {
+        solve(s, 3)
+    }
+
+    fn part2(s: &S) -> usize {
+        solve(s, 26)
+    }
 }
 
 type S = Vec<String>;
@@ -31,34 +44,26 @@ type S = Vec<String>;
 
 lazy_static! {
     static ref POSITIONS: HashMap<char, Pos> = HashMap::from([
-        ('7', (0, 0)),
-        ('8', (1, 0)),
-        ('9', (2, 0)),
-        ('4', (0, 1)),
-        ('5', (1, 1)),
-        ('6', (2, 1)),
-        ('1', (0, 2)),
-        ('2', (1, 2)),
-        ('3', (2, 2)),
-        ('0', (1, 3)),
-        ('^', (1, 3)),
-        ('A', (2, 3)),
-        ('<', (0, 4)),
-        ('v', (1, 4)),
-        ('>', (2, 4)),
+        ('7', Pos::new(0, 0)),
+        ('8', Pos::new(1, 0)),
+        ('9', Pos::new(2, 0)),
+        ('4', Pos::new(0, 1)),
+        ('5', Pos::new(1, 1)),
+        ('6', Pos::new(2, 1)),
+        ('1', Pos::new(0, 2)),
+        ('2', Pos::new(1, 2)),
+        ('3', Pos::new(2, 2)),
+        ('0', Pos::new(1, 3)),
+        ('^', Pos::new(1, 3)),
+        ('A', Pos::new(2, 3)),
+        ('<', Pos::new(0, 4)),
+        ('v', Pos::new(1, 4)),
+        ('>', Pos::new(2, 4)),
     ]);
 }
 
-fn part1(s: &S) -> usize {
-    solve(s, 3)
-}
-
-fn part2(s: &S) -> usize {
-    solve(s, 26)
-}
-
 fn solve(s: &S, depth: u8) -> usize {
-    s.into_iter().map(|c| solve_one(c, depth)).sum()
+    s.iter().map(|c| solve_one(c, depth)).sum()
 }
 
 fn solve_one(c: &str, depth: u8) -> usize {
@@ -109,7 +114,7 @@ fn paths(from: char, to: char) -> Vec<String> {
     let from_pos = *POSITIONS.get(&from).unwrap();
     let to_pos = *POSITIONS.get(&to).unwrap();
 
-    let (dx, dy) = pos_sub(to_pos, from_pos);
+    let Pos { x: dx, y: dy } = to_pos - from_pos;
 
     let horizontal = if dx > 0 {
         ">".repeat(dx as usize)
@@ -127,12 +132,12 @@ fn paths(from: char, to: char) -> Vec<String> {
         "".to_string()
     };
 
-    if from_pos.0 == 0 && to_pos.1 == 3 {
+    if from_pos.x == 0 && to_pos.y == 3 {
         // We go from the first column to row 3
         // So we always go horizontal fist
         return Vec::from([horizontal + &vertical + "A"]);
     }
-    if from_pos.1 == 3 && to_pos.0 == 0 {
+    if from_pos.y == 3 && to_pos.x == 0 {
         // We go from row 3 to column 0
         // So we always go vertical first
         return Vec::from([vertical + &horizontal + "A"]);
@@ -147,15 +152,3 @@ fn paths(from: char, to: char) -> Vec<String> {
         vertical + &horizontal + "A",
     ])
 }
-
-#[allow(dead_code)]
-const TEST_INPUT: &str = "029A
-980A
-179A
-456A
-379A";
-
-#[test]
-fn test_part1() {
-    advent_of_code_24::test1(TEST_INPUT, 126384, input::as_lines, part1);
-}
@@ -1,9 +1,102 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-use itertools::{Itertools, TupleCombinations};
+use itertools::Itertools;
 
-fn main() {
-    advent_of_code_24::solve("day24", parse, part1, part2);
+use crate::{input, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = S;
+    type Part1 = u64;
+    type Part2 = String;
+
+    fn parse(s: &str) -> S {
+        let [wires, gates] = input::sections(s, ["wires", "gates"]);
+        let registers = wires
+            .parse(|s| input::try_key_values::<String, u8>(s, ": "))
+            .into_iter()
+            .map(|(reg, val)| (reg, val == 1))
+            .collect_vec();
+        let gates = gates
+            .parse(|s| input::try_scan_all("{} {} {} -> {}", s))
+            .into_iter()
+            .map(|(left, op, right, out): (String, String, String, String)| {
+                (left, right, Op::new(&op), out)
+            })
+            .collect_vec();
+        (registers, gates)
+    }
+
+    fn part1((start, gate_instr): &S) -> u64 {
+        let (mut gates, gate_lookup) = make_gates(gate_instr);
+        let mut queue = start.clone();
+        let mut outputs = Vec::new();
+        while let Some((in_wire, in_val)) = queue.pop() {
+            let input_for = gate_lookup.get(&in_wire);
+            if input_for.is_none() {
+                outputs.push((in_wire, in_val));
+                continue;
+            }
+            for gate_idx in input_for.unwrap() {
+                if let Some(out) = gates[*gate_idx].load(in_val) {
+                    queue.push(out);
+                }
+            }
+        }
+
+        outputs.sort();
+        outputs.reverse();
+        outputs
+            .into_iter()
+            .fold(0, |acc, (_, val)| (acc << 1) | if val { 1 } else { 0 })
+    }
+
+    fn part2((start, gates): &S) -> String {
+        let (init_gates, gate_lookup) = make_gates(gates);
+
+        let (size, _) = start
+            .iter()
+            .enumerate()
+            .find(|(_, (wire, _))| wire == "y00")
+            .unwrap();
+
+        // Find swap candidates
+        let mut swap_candidates = Vec::new();
+        for i in 0..size {
+            let test_res = test_bit(&init_gates, &gate_lookup, i);
+
+            if let Some(cand) = test_res {
+                let mut bit_swap_candidates = Vec::new();
+                for (c1, c2) in cand.iter().tuple_combinations() {
+                    if test_bit_with_swaps(&init_gates, &gate_lookup, i, vec![(*c1, *c2)]) {
+                        bit_swap_candidates.push((*c1, *c2));
+                    }
+                }
+                if !bit_swap_candidates.is_empty() {
+                    swap_candidates.push(bit_swap_candidates);
+                }
+            }
+        }
+
+        let mut solution = Vec::new();
+        for swaps in swap_candidates
+            .into_iter()
+            .map(|v| v.into_iter())
+            .multi_cartesian_product()
+        {
+            if test_full_with_swaps(&init_gates, &gate_lookup, size, swaps.clone()) {
+                solution = swaps;
+                break;
+            }
+        }
+
+        solution
+            .iter()
+            .flat_map(|(a, b)| [init_gates[*a].out.clone(), init_gates[*b].out.clone()])
+            .sorted()
+            .join(",")
+    }
 }
 
 type Sig = bool;
@@ -23,56 +116,6 @@ ccn AND tdp -> tkr
 tdp XOR ccn -> z02
  */
 
-fn parse(s: &str) -> S {
-    let (register_str, gate_str) = s.split_once("\n\n").unwrap();
-    let registers = register_str
-        .lines()
-        .map(|line| {
-            let (reg, val_str) = line.split_once(": ").unwrap();
-            (reg.to_owned(), val_str == "1")
-        })
-        .collect_vec();
-    let gates = gate_str
-        .lines()
-        .map(|line| {
-            let (rest, out) = line.split_once(" -> ").unwrap();
-            let (left, op_str, right) = rest.split_whitespace().next_tuple().unwrap();
-            (
-                left.to_owned(),
-                right.to_owned(),
-                Op::new(op_str),
-                out.to_owned(),
-            )
-        })
-        .collect_vec();
-    (registers, gates)
-}
-
-fn part1((start, gate_instr): &S) -> String {
-    let (mut gates, gate_lookup) = make_gates(gate_instr);
-    let mut queue = start.clone();
-    let mut outputs = Vec::new();
-    while let Some((in_wire, in_val)) = queue.pop() {
-        let input_for = gate_lookup.get(&in_wire);
-        if input_for.is_none() {
-            outputs.push((in_wire, in_val));
-            continue;
-        }
-        for gate_idx in input_for.unwrap() {
-            if let Some(out) = gates[*gate_idx].load(in_val) {
-                queue.push(out);
-            }
-        }
-    }
-
-    outputs.sort();
-    outputs.reverse();
-    outputs
-        .into_iter()
-        .fold(0, |acc, (_, val)| (acc << 1) | if val { 1 } else { 0 })
-        .to_string()
-}
-
 fn make_gates(
     gate_instr: &Vec<(String, String, Op, String)>,
 ) -> (Vec<Gate>, HashMap<String, Vec<usize>>) {
@@ -96,52 +139,6 @@ fn make_gates(
 type Gates = Vec<Gate>;
 type GateLookup = HashMap<String, Vec<usize>>;
 
-fn part2((start, gates): &S) -> String {
-    let (init_gates, gate_lookup) = make_gates(gates);
-
-    let (size, _) = start
-        .iter()
-        .enumerate()
-        .find(|(_, (wire, _))| wire == "y00")
-        .unwrap();
-
-    // Find swap candidates
-    let mut swap_candidates = Vec::new();
-    for i in 0..size {
-        let test_res = test_bit(&init_gates, &gate_lookup, i);
-
-        if let Some(cand) = test_res {
-            let mut bit_swap_candidates = Vec::new();
-            for (c1, c2) in cand.iter().tuple_combinations() {
-                if test_bit_with_swaps(&init_gates, &gate_lookup, i, vec![(*c1, *c2)]) {
-                    bit_swap_candidates.push((*c1, *c2));
-                }
-            }
-            if !bit_swap_candidates.is_empty() {
-                swap_candidates.push(bit_swap_candidates);
-            }
-        }
-    }
-
-    let mut solution = Vec::new();
-    for swaps in swap_candidates
-        .into_iter()
-        .map(|v| v.into_iter())
-        .multi_cartesian_product()
-    {
-        if test_full_with_swaps(&init_gates, &gate_lookup, size, swaps.clone()) {
-            solution = swaps;
-            break;
-        }
-    }
-
-    solution
-        .iter()
-        .flat_map(|(a, b)| [init_gates[*a].out.clone(), init_gates[*b].out.clone()])
-        .sorted()
-        .join(",")
-}
-
 fn test_full_with_swaps(
     gates: &Gates,
     gate_lookup: &GateLookup,
@@ -204,7 +201,7 @@ fn test_full_with_swaps(
             return false;
         }
     }
-    return true;
+    true
 }
 
 fn test_bit_with_swaps(
@@ -220,7 +217,7 @@ fn test_bit_with_swaps(
         fixed_gates[c2].out = tmp;
     }
 
-    test_bit(&fixed_gates, &gate_lookup, bit).is_none()
+    test_bit(&fixed_gates, gate_lookup, bit).is_none()
 }
 
 fn test_bit(gates: &Gates, gate_lookup: &GateLookup, bit: usize) -> Option<HashSet<usize>> {
@@ -295,8 +292,9 @@ fn wlabel(prefix: &str, num: usize) -> String {
     format!("{}{:02}", prefix, num)
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum Op {
+pub enum Op {
     AND,
     OR,
     XOR,
@@ -348,74 +346,3 @@ impl Gate {
         }
     }
 }
-
-#[allow(dead_code)]
-const TEST_INPUT: &str = "x00: 1
-x01: 1
-x02: 1
-y00: 0
-y01: 1
-y02: 0
-
-x00 AND y00 -> z00
-x01 XOR y01 -> z01
-x02 OR y02 -> z02";
-
-#[test]
-fn test_part1() {
-    advent_of_code_24::test1(TEST_INPUT, "4".to_string(), parse, part1);
-}
-
-#[allow(dead_code)]
-const TEST_INPUT2: &str = "x00: 1
-x01: 0
-x02: 1
-x03: 1
-x04: 0
-y00: 1
-y01: 1
-y02: 1
-y03: 1
-y04: 1
-
-ntg XOR fgs -> mjb
-y02 OR x01 -> tnw
-kwq OR kpj -> z05
-x00 OR x03 -> fst
-tgd XOR rvg -> z01
-vdt OR tnw -> bfw
-bfw AND frj -> z10
-ffh OR nrd -> bqk
-y00 AND y03 -> djm
-y03 OR y00 -> psh
-bqk OR frj -> z08
-tnw OR fst -> frj
-gnj AND tgd -> z11
-bfw XOR mjb -> z00
-x03 OR x00 -> vdt
-gnj AND wpb -> z02
-x04 AND y00 -> kjc
-djm OR pbm -> qhw
-nrd AND vdt -> hwm
-kjc AND fst -> rvg
-y04 OR y02 -> fgs
-y01 AND x02 -> pbm
-ntg OR kjc -> kwq
-psh XOR fgs -> tgd
-qhw XOR tgd -> z09
-pbm OR djm -> kpj
-x03 XOR y03 -> ffh
-x00 XOR y04 -> ntg
-bfw OR bqk -> z06
-nrd XOR fgs -> wpb
-frj XOR qhw -> z04
-bqk OR frj -> z07
-y03 OR x01 -> nrd
-hwm AND bqk -> z03
-tgd XOR rvg -> z12
-tnw OR pbm -> gnj";
-
-#[test]
-fn test_part1_large() {
-    advent_of_code_24::test1(TEST_INPUT2, "2024".to_string(), parse, part1);
-}
@@ -0,0 +1,31 @@
+use crate::Solution;
+
+use regex::Regex;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(s: &str) -> String {
+        s.to_owned()
+    }
+
+    fn part1(content: &String) -> i32 {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)").expect("cannot compile regex");
+        re.captures_iter(content)
+            .map(|c| {
+                let (_, [x, y]) = c.extract();
+                x.parse::<i32>().unwrap() * y.parse::<i32>().unwrap()
+            })
+            .sum()
+    }
+
+    fn part2(content: &String) -> i32 {
+        let re = Regex::new(r"(?s)don't\(\).*?(do\(\)|$)").expect("cannot compile regex");
+        let stripped = re.replace_all(content, "");
+        Self::part1(&stripped.into_owned())
+    }
+}
@@ -0,0 +1,125 @@
+use std::cmp::min;
+
+use crate::{input, square::Grid, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(s: &str) -> Grid<char> {
+        input::as_grid(s)
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        let content = &lines(grid);
+        let transposed = &lines(&grid.transpose());
+        let block_sums: [usize; 8] = [
+            content,
+            transposed,
+            &reverse(content),
+            &reverse(transposed),
+            &diagonals(content),
+            &diagonals(&flip(content)),
+            &diagonals(&reverse(content)),
+            &diagonals(&reverse(&flip(content))),
+        ]
+        .map(|m| m.iter().map(|l| find_xmas(l)).sum());
+        block_sums.iter().sum()
+    }
+
+    fn part2(mat: &Grid<char>) -> usize {
+        let m = mat.height() as isize;
+        let n = mat.width() as isize;
+
+        let mut count = 0;
+        for i in 1..m - 1 {
+            for j in 1..n - 1 {
+                let at = |di: isize, dj: isize| mat[(j + dj, i + di)];
+                if at(0, 0) != 'A' {
+                    continue;
+                }
+                let mut xmasses = 0;
+                if at(-1, -1) == 'M' && at(1, 1) == 'S' {
+                    xmasses += 1
+                }
+                if at(-1, 1) == 'M' && at(1, -1) == 'S' {
+                    xmasses += 1
+                }
+                if at(1, -1) == 'M' && at(-1, 1) == 'S' {
+                    xmasses += 1
+                }
+                if at(1, 1) == 'M' && at(-1, -1) == 'S' {
+                    xmasses += 1
+                }
+                if xmasses >= 2 {
+                    count += 1
+                }
+            }
+        }
+        count
+    }
+}
+
+/// Lines of letters to search, not necessarily of equal length.
+type Lines = Vec<Vec<char>>;
+
+fn lines(grid: &Grid<char>) -> Lines {
+    grid.rows().map(<[char]>::to_vec).collect()
+}
+
+fn find_xmas(chars: &[char]) -> usize {
+    chars
+        .iter()
+        .collect::<String>()
+        .match_indices("XMAS")
+        .count()
+}
+
+fn flip(content: &Lines) -> Lines {
+    content.iter().rev().cloned().collect()
+}
+
+fn reverse(content: &Lines) -> Lines {
+    content
+        .iter()
+        .map(|line| line.iter().rev().collect::<String>())
+        .map(|s| s.chars().collect())
+        .collect()
+}
+
+fn diagonals(content: &Lines) -> Lines {
+    let mut vec: Lines = Vec::new();
+    let m: isize = content.len().try_into().unwrap();
+    let n: isize = content[0].len().try_into().unwrap();
+    // lower diagonals
+    for d in 1..m {
+        let mut diag = Vec::new();
+        for i in 0..min(m - d, n) {
+            diag.push(content[(i + d) as usize][i as usize]);
+        }
+        vec.push(diag);
+    }
+    // upper diagonals
+    for d in 0..n {
+        let mut diag = Vec::new();
+        for i in 0..min(m, n - d) {
+            diag.push(content[i as usize][(i + d) as usize]);
+        }
+        vec.push(diag);
+    }
+    vec
+}
+
+#[test]
+fn test_diagonals() {
+    let mat: Lines = Vec::from(["abc", "def", "ghi", "jkl"].map(|l| l.chars().collect()));
+    let want: Lines = Vec::from(["dhl", "gk", "j", "aei", "bf", "c"].map(|l| l.chars().collect()));
+    assert_eq!(diagonals(&mat), want);
+
+    let mat: Lines = Vec::from(["abcd", "efgh", "ijkl"].map(|l| l.chars().collect()));
+    let want: Lines = Vec::from(["ej", "i", "afk", "bgl", "ch", "d"].map(|l| l.chars().collect()));
+    assert_eq!(diagonals(&mat), want);
+}
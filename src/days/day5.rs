@@ -0,0 +1,86 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use crate::{input, Solution};
+
+type Rules = Vec<(u32, u32)>;
+type Pages = Vec<Vec<u32>>;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Rules, Pages);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(inp: &str) -> (Rules, Pages) {
+        let [rules, pages] = input::sections(inp, ["rules", "pages"]);
+        (
+            rules.parse(|s| input::try_scan_all("{}|{}", s)),
+            pages.parse(|s| input::try_as_separated(s, ",")),
+        )
+    }
+
+    fn part1((rules, pages): &(Rules, Pages)) -> u32 {
+        let mut order: HashSet<(u32, u32)> = HashSet::new();
+        for r in rules {
+            order.insert(*r);
+        }
+
+        let mut value = 0;
+        for ps in pages {
+            let mut violates = false;
+            for i in 0..ps.len() - 1 {
+                for j in i + 1..ps.len() {
+                    if order.contains(&(ps[j], ps[i])) {
+                        violates = true;
+                        break;
+                    }
+                }
+            }
+            if !violates {
+                value += ps[ps.len() / 2];
+            }
+        }
+        value
+    }
+
+    fn part2((rules, pages): &(Rules, Pages)) -> u32 {
+        let mut order: HashSet<(u32, u32)> = HashSet::new();
+        for r in rules {
+            order.insert(*r);
+        }
+
+        let mut incorrect: Pages = Vec::new();
+        for ps in pages {
+            let mut violates = false;
+            for i in 0..ps.len() - 1 {
+                for j in i + 1..ps.len() {
+                    if order.contains(&(ps[j], ps[i])) {
+                        violates = true;
+                        break;
+                    }
+                }
+            }
+            if violates {
+                incorrect.push(ps.to_vec());
+            }
+        }
+
+        let mut value = 0;
+        for mut ps in incorrect {
+            ps.sort_by(|a, b| {
+                if order.contains(&(*a, *b)) {
+                    Ordering::Less
+                } else if order.contains(&(*b, *a)) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+
+            value += ps[ps.len() / 2];
+        }
+
+        value
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    input::{self, Marker},
+    render::{self, Overlay, Rgb},
+    square::{Dir4, Grid, Pos},
+    Solution,
+};
+
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+type M = Grid<u8>;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (M, Pos);
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(s: &str) -> (M, Pos) {
+        let (mat, found) = input::as_grid_with_markers(s, &[Marker::one('^', b'.')]);
+        (mat, found.pos('^'))
+    }
+
+    fn part1((mat, start): &(M, Pos)) -> u16 {
+        let mut guard = Guard::new(mat, *start);
+        loop {
+            if guard.step() {
+                break;
+            }
+        }
+        // We wont move off the board, so we have to count the last one extra.
+        guard.dist + 1
+    }
+
+    /// The guard's path until it leaves the map.
+    fn render((mat, start): &(M, Pos)) -> Option<String> {
+        let mut guard = Guard::new(mat, *start);
+        loop {
+            if guard.step() {
+                break;
+            }
+        }
+        Some(guard.display())
+    }
+
+    fn part2((mat, start): &(M, Pos)) -> u16 {
+        (0..mat.height() as isize)
+            .collect::<Vec<isize>>()
+            .par_iter()
+            .map(|y| {
+                let mut found_loops = 0;
+                for x in 0..mat.width() as isize {
+                    if mat[(x, *y)] != b'.' || *start == Pos::new(x, *y) {
+                        continue;
+                    }
+
+                    let mut m = mat.clone();
+                    m[(x, *y)] = b'O';
+
+                    let mut guard = Guard::new(&m, *start);
+                    loop {
+                        if guard.step() {
+                            break;
+                        }
+                    }
+                    if guard.found_loop {
+                        found_loops += 1;
+                    }
+                }
+                found_loops
+            })
+            .sum()
+    }
+}
+
+#[derive(Clone)]
+struct Guard {
+    mat: M,
+    pos: Pos,
+    dir: Dir4,
+    dist: u16,
+    seen: HashSet<(Pos, Dir4)>,
+    found_loop: bool,
+}
+
+impl Guard {
+    fn new(mat: &M, pos: Pos) -> Self {
+        Self {
+            mat: mat.clone(),
+            pos,
+            dir: Dir4::North,
+            dist: 0,
+            seen: HashSet::from([(pos, Dir4::North)]),
+            found_loop: false,
+        }
+    }
+
+    fn peek_forward(&self) -> Option<u8> {
+        self.mat.get(self.forward_pos()?).copied()
+    }
+
+    fn forward_pos(&self) -> Option<Pos> {
+        let pos = self.pos + self.dir;
+        if self.mat.in_bounds(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if let Some(cell) = self.peek_forward() {
+            match cell as char {
+                '.' | 'X' => {
+                    self.move_forward();
+                }
+                '#' | 'O' => {
+                    self.rotate_right();
+                }
+                _ => panic!("invalid cell"),
+            }
+
+            // Check if we have been here before
+            if self.seen.contains(&(self.pos, self.dir)) {
+                self.found_loop = true;
+                true
+            } else {
+                false
+            }
+        } else {
+            true
+        }
+    }
+
+    fn rotate_right(&mut self) {
+        self.seen.insert((self.pos, self.dir));
+        self.dir = self.dir.turn_right();
+    }
+
+    fn move_forward(&mut self) {
+        self.seen.insert((self.pos, self.dir));
+        if self.mat[self.pos] != b'X' {
+            // Only count distinct positions
+            self.dist += 1;
+        }
+        self.mat[self.pos] = b'X';
+        self.pos = self.forward_pos().unwrap();
+    }
+
+    /// The map with the visited cells highlighted, for a terminal.
+    fn display(&self) -> String {
+        let visited = Overlay::new(self.seen.iter().map(|(p, _)| *p), Rgb::YELLOW);
+        let guard = Overlay::with_glyphs([(self.pos, self.dir.arrow())], Rgb::RED);
+        render::ansi(&self.mat, |c| *c as char, &[visited, guard])
+    }
+}
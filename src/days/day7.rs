@@ -1,29 +1,37 @@
-use advent_of_code_24::input;
+use crate::{input, Solution};
 
-fn main() {
-    let inp = input::as_lines(&input::read("day7"));
-    println!("part1: {}", part1(&inp));
-    println!("part2: {}", part2(&inp));
-}
+pub struct Day7;
 
-fn part1(lines: &Vec<String>) -> i64 {
-    lines
-        .iter()
-        .map(Eqn::new_part1)
-        .filter(Eqn::has_soln)
-        .map(|e| e.tot)
-        .sum()
-}
+impl Solution for Day7 {
+    type Input = S;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> S {
+        input::as_lines(s)
+    }
+
+    fn part1(lines: &S) -> i64 {
+        lines
+            .iter()
+            .map(|l| Eqn::new_part1(l))
+            .filter(Eqn::has_soln)
+            .map(|e| e.tot)
+            .sum()
+    }
 
-fn part2(lines: &Vec<String>) -> i64 {
-    lines
-        .iter()
-        .map(Eqn::new_part2)
-        .filter(Eqn::has_soln)
-        .map(|e| e.tot)
-        .sum()
+    fn part2(lines: &S) -> i64 {
+        lines
+            .iter()
+            .map(|l| Eqn::new_part2(l))
+            .filter(Eqn::has_soln)
+            .map(|e| e.tot)
+            .sum()
+    }
 }
 
+type S = Vec<String>;
+
 #[derive(Clone)]
 struct Eqn {
     tot: i64,
@@ -32,10 +40,12 @@ struct Eqn {
 }
 
 impl Eqn {
-    fn new_part1(line: &String) -> Self {
+    fn new_part1(line: &str) -> Self {
         let (tot_str, val_str) = line.split_once(": ").unwrap();
         Self {
-            tot: tot_str.parse().expect(&format!("cannot parse {tot_str}")),
+            tot: tot_str
+                .parse()
+                .unwrap_or_else(|_| panic!("cannot parse {tot_str}")),
             terms: val_str
                 .split_whitespace()
                 .map(|n| n.parse().unwrap())
@@ -44,10 +54,12 @@ impl Eqn {
         }
     }
 
-    fn new_part2(line: &String) -> Self {
+    fn new_part2(line: &str) -> Self {
         let (tot_str, val_str) = line.split_once(": ").unwrap();
         Self {
-            tot: tot_str.parse().expect(&format!("cannot parse {tot_str}")),
+            tot: tot_str
+                .parse()
+                .unwrap_or_else(|_| panic!("cannot parse {tot_str}")),
             terms: val_str
                 .split_whitespace()
                 .map(|n| n.parse().unwrap())
@@ -104,35 +116,3 @@ impl Eqn {
         false
     }
 }
-
-#[test]
-fn test_part1() {
-    let inp = input::as_lines(
-        "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20",
-    );
-    assert_eq!(part1(&inp), 3749);
-}
-
-#[test]
-fn test_part2() {
-    let inp = input::as_lines(
-        "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20",
-    );
-    assert_eq!(part2(&inp), 11387);
-}
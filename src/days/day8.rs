@@ -1,54 +1,59 @@
 use std::collections::{HashMap, HashSet};
 
-use advent_of_code_24::input;
+use crate::{input, square::Grid, Solution};
 
-type M = Vec<Vec<char>>;
+type M = Grid<char>;
 
 type Coord = (usize, usize);
 
-fn main() {
-    let inp: M = input::as_vecvec(&input::read("day8"));
-    println!("part1: {}", part1(&inp));
-    println!("part2: {}", part2(&inp));
-}
+pub struct Day8;
 
-fn part1(inp: &M) -> usize {
-    let max = (inp.len(), inp[0].len());
-    let antinodes: HashSet<Coord> = find_antennas(inp)
-        .iter()
-        .flat_map(|(_, vs)| {
-            make_pairs(vs)
-                .iter()
-                .flat_map(|(a, b)| leap2(max, (**a, **b)))
-                .collect::<Vec<Coord>>()
-        })
-        .collect();
-    antinodes.len()
-}
+impl Solution for Day8 {
+    type Input = M;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(s: &str) -> M {
+        input::as_grid(s)
+    }
+
+    fn part1(inp: &M) -> usize {
+        let max = (inp.height(), inp.width());
+        let antinodes: HashSet<Coord> = find_antennas(inp)
+            .iter()
+            .flat_map(|(_, vs)| {
+                make_pairs(vs)
+                    .iter()
+                    .flat_map(|(a, b)| leap2(max, (**a, **b)))
+                    .collect::<Vec<Coord>>()
+            })
+            .collect();
+        antinodes.len()
+    }
 
-fn part2(inp: &M) -> usize {
-    let max = (inp.len(), inp[0].len());
-    let antinodes: HashSet<Coord> = find_antennas(inp)
-        .iter()
-        .flat_map(|(_, vs)| {
-            make_pairs(vs)
-                .iter()
-                .flat_map(|(a, b)| leap(max, (**a, **b)))
-                .collect::<Vec<Coord>>()
-        })
-        .collect();
-    antinodes.len()
+    fn part2(inp: &M) -> usize {
+        let max = (inp.height(), inp.width());
+        let antinodes: HashSet<Coord> = find_antennas(inp)
+            .iter()
+            .flat_map(|(_, vs)| {
+                make_pairs(vs)
+                    .iter()
+                    .flat_map(|(a, b)| leap(max, (**a, **b)))
+                    .collect::<Vec<Coord>>()
+            })
+            .collect();
+        antinodes.len()
+    }
 }
 
 fn find_antennas(m: &M) -> Vec<(char, Vec<Coord>)> {
     let mut antennas = Vec::new();
-    for i in 0..m.len() {
-        for j in 0..m[i].len() {
-            let c = m[i][j];
-            if c == '.' {
+    for (i, row) in m.rows().enumerate() {
+        for (j, c) in row.iter().enumerate() {
+            if *c == '.' {
                 continue;
             }
-            antennas.push((c, (i, j)));
+            antennas.push((*c, (i, j)));
         }
     }
     // Collect Vec<(A,B)> into Vec<A,Vec<B>>
@@ -59,7 +64,7 @@ fn find_antennas(m: &M) -> Vec<(char, Vec<Coord>)> {
     groups.into_iter().collect()
 }
 
-fn make_pairs<'a, T>(v: &'a Vec<T>) -> Vec<(&'a T, &'a T)> {
+fn make_pairs<T>(v: &[T]) -> Vec<(&T, &T)> {
     let mut pairs = Vec::new();
     for i in 0..v.len() - 1 {
         for j in i + 1..v.len() {
@@ -112,41 +117,3 @@ fn leap(max: Coord, (a, b): (Coord, Coord)) -> Vec<Coord> {
     }
     res
 }
-
-#[test]
-fn test_part1() {
-    let inp = input::as_vecvec(
-        "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............",
-    );
-    assert_eq!(part1(&inp), 14);
-}
-
-#[test]
-fn test_part2() {
-    let inp = input::as_vecvec(
-        "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............",
-    );
-    assert_eq!(part2(&inp), 34);
-}
@@ -0,0 +1,103 @@
+use crate::{
+    bench::{BenchConfig, Stats},
+    input,
+    solution::{Runner, Solved},
+    Parts,
+};
+
+/// A registered puzzle day.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn Runner,
+}
+
+impl Day {
+    /// Reads the input `name`d on the command line (see [`input::Source::from_arg`]),
+    /// or the real input if there is none.
+    pub fn input(&self, name: Option<&str>) -> std::io::Result<String> {
+        let source = match name {
+            Some(name) => input::Source::from_arg(self.day, name),
+            None => input::Source::day(self.day),
+        };
+        input::read(&source)
+    }
+
+    pub fn solve(&self, input: &str, parts: Parts) -> Vec<Solved> {
+        self.solution.solve(input, parts)
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        parts: Parts,
+        config: &BenchConfig,
+    ) -> Vec<(&'static str, Stats)> {
+        self.solution.bench(input, parts, config)
+    }
+
+    pub fn render(&self, input: &str) -> Option<String> {
+        self.solution.render(input)
+    }
+}
+
+macro_rules! days {
+    ($($day:literal => $module:ident::$solution:ident,)*) => {
+        $(pub mod $module;)*
+
+        /// All registered days, in calendar order.
+        pub const DAYS: &[Day] = &[$(Day {
+            day: $day,
+            solution: &$module::$solution,
+        }),*];
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+}
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// One test per file in `examples/dayN`, generated by `build.rs`.
+#[cfg(test)]
+mod examples {
+    fn check(day: u8, name: &str, content: &str) {
+        let day = super::get(day).unwrap_or_else(|| panic!("day{} is not registered", day));
+        let example = crate::examples::Example::parse(name, content).unwrap();
+        let failures = crate::examples::check_example(day, &example);
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[test]
+fn test_days_sorted() {
+    assert!(DAYS.windows(2).all(|w| w[0].day < w[1].day));
+}
@@ -0,0 +1,112 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{days::Day, Parts};
+
+/// A puzzle example with the answers it is known to produce.
+///
+/// Stored as `examples/dayN/<name>`: lines of `partN: <answer>`, a blank
+/// line, then the input. Parts without an answer are not checked, so an
+/// example starting with the blank line checks nothing yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(u8, String)>,
+}
+
+impl Example {
+    pub fn parse(name: &str, content: &str) -> Result<Self, String> {
+        let (header, input) = match content.strip_prefix('\n') {
+            Some(input) => ("", input),
+            None => content.split_once("\n\n").ok_or(format!(
+                "example {} has no blank line after its answers",
+                name
+            ))?,
+        };
+        let expected = header
+            .lines()
+            .map(|line| {
+                line.strip_prefix("part")
+                    .and_then(|l| l.split_once(": "))
+                    .and_then(|(part, answer)| Some((part.parse().ok()?, answer.to_owned())))
+                    .ok_or(format!("example {}: invalid answer line {:?}", name, line))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            name: name.to_owned(),
+            input: input.to_owned(),
+            expected,
+        })
+    }
+}
+
+pub fn dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day{}", day))
+}
+
+/// All examples of `day`, sorted by name.
+pub fn load(day: u8) -> io::Result<Vec<Example>> {
+    let entries = match fs::read_dir(dir(day)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut examples = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let content = fs::read_to_string(entry.path())?;
+        examples.push(
+            Example::parse(&name, &content)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        );
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Solves every example of `day`, describing each wrong answer.
+pub fn check(day: &Day) -> Vec<String> {
+    let examples = match load(day.day) {
+        Ok(examples) => examples,
+        Err(e) => return vec![format!("day{}: {}", day.day, e)],
+    };
+    examples
+        .iter()
+        .flat_map(|example| check_example(day, example))
+        .collect()
+}
+
+/// Solves one example of `day`, describing each wrong answer.
+pub fn check_example(day: &Day, example: &Example) -> Vec<String> {
+    let mut failures = Vec::new();
+    for (part, want) in &example.expected {
+        for solved in day.solve(&example.input, Parts::Only(*part)) {
+            if !solved.answer.matches(want) {
+                failures.push(format!(
+                    "day{} {} part{}: got {}, expected {}",
+                    day.day, example.name, part, solved.answer, want
+                ));
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_parse_example() {
+    let example = Example::parse("small", "part1: 4\npart2: co,de\n\n1 2\n\n3\n").unwrap();
+    assert_eq!(example.input, "1 2\n\n3\n");
+    assert_eq!(
+        example.expected,
+        vec![(1, "4".to_owned()), (2, "co,de".to_owned())]
+    );
+    assert_eq!(Example::parse("new", "\n1 2").unwrap().expected, vec![]);
+    assert!(Example::parse("bad", "1 2\n3").is_err());
+    assert!(Example::parse("bad", "answer: 4\n\n1 2").is_err());
+}
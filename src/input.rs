@@ -1,29 +1,231 @@
-extern crate nalgebra as na;
+use std::{
+    collections::HashMap,
+    format, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use std::{format, fs, str::FromStr};
+use crate::square::{Dir4, Grid, Pos};
 
-pub fn read(day: &str) -> String {
-    let path = format!("inputs/{}", day);
-    fs::read_to_string(&path).expect(&format!("input file {} not found", &path))
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// Name of the default input of a day, stored as `<root>/dayN`.
+pub const REAL: &str = "real";
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A named input of a day: `<root>/dayN` for [`REAL`], `<root>/dayN.<name>` otherwise.
+    Named {
+        day: u8,
+        name: String,
+    },
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// The real input of `day`.
+    pub fn day(day: u8) -> Self {
+        Self::Named {
+            day,
+            name: REAL.to_owned(),
+        }
+    }
+
+    /// `-` reads stdin, anything containing a path separator is a file,
+    /// everything else names an input of `day`.
+    pub fn from_arg(day: u8, arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else if arg.contains(std::path::is_separator) {
+            Self::Path(arg.into())
+        } else {
+            Self::Named {
+                day,
+                name: arg.to_owned(),
+            }
+        }
+    }
+
+    /// The file this source reads, if any.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Self::Named { day, name } if name == REAL => Some(root().join(format!("day{}", day))),
+            Self::Named { day, name } => Some(root().join(format!("day{}.{}", day, name))),
+            Self::Path(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+}
+
+/// Directory holding the named inputs: `$AOC_INPUTS` if set, else `inputs`
+/// in the current directory, falling back to the one in this crate.
+pub fn root() -> PathBuf {
+    if let Some(root) = std::env::var_os(INPUTS_ENV) {
+        return root.into();
+    }
+    let local = PathBuf::from("inputs");
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+    }
+}
+
+/// Names of all inputs of `day` found in the inputs root, sorted.
+pub fn names(day: u8) -> Vec<String> {
+    let prefix = format!("day{}", day);
+    let mut names: Vec<String> = fs::read_dir(root())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file = entry.ok()?.file_name().into_string().ok()?;
+            match file.strip_prefix(&prefix)? {
+                "" => Some(REAL.to_owned()),
+                rest => rest.strip_prefix('.').map(String::from),
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn read(source: &Source) -> io::Result<String> {
+    match source.path() {
+        Some(path) => fs::read_to_string(&path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("cannot read input {}: {}", path.display(), e),
+            )
+        }),
+        None => {
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content)?;
+            Ok(content)
+        }
+    }
 }
 
 pub fn line(content: &str) -> String {
     content.trim().to_owned()
 }
 
-pub fn single_line_vec<'a, T>(content: &'a str) -> Vec<T>
+/// Location and cause of a failure to parse puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    /// Offending token, empty if a field was missing.
+    pub token: String,
+    /// What was expected at this location.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            token: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "{:?}", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// How forgiving the `try_` parsers are about the shape of the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Extra fields are ignored and rows may differ in length.
+    #[default]
+    Lenient,
+    /// Trailing fields, extra lines and ragged rows are errors.
+    Strict,
+}
+
+/// Whitespace separated tokens of `line` with their 1-based column.
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count() + 1, token)
+    })
+}
+
+fn parse_token<T: FromStr>(line: usize, column: usize, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, column, token, std::any::type_name::<T>()))
+}
+
+/// In strict mode, every row needs as many cells as the first one.
+fn check_rows<T>(rows: &[Vec<T>], lines: &[&str], mode: Mode) -> Result<(), ParseError> {
+    if mode == Mode::Lenient {
+        return Ok(());
+    }
+    let Some(width) = rows.first().map(Vec::len) else {
+        return Ok(());
+    };
+    for (i, row) in rows.iter().enumerate().skip(1) {
+        if row.len() != width {
+            let found = lines[i].chars().nth(width).map(String::from);
+            return Err(ParseError::new(
+                i + 1,
+                width.min(row.len()) + 1,
+                found.as_deref().unwrap_or(""),
+                format!("a row of {} cells", width),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn single_line_vec<T>(content: &str) -> Vec<T>
 where
-    T: FromStr + std::fmt::Debug,
-    <T as FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    content
-        .trim()
-        .split_whitespace()
-        .map(|x| {
-            x.parse::<T>()
-                .expect(&format!("cannot read {} as number", x))
-        })
-        .collect()
+    try_single_line_vec(content, Mode::Lenient).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Whitespace separated values; strict mode allows only a single line.
+pub fn try_single_line_vec<T>(content: &str, mode: Mode) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+{
+    let mut values = Vec::new();
+    let mut first_line = None;
+    for (i, line) in content.lines().enumerate() {
+        for (column, token) in tokens(line) {
+            match first_line {
+                Some(first) if mode == Mode::Strict && first != i => {
+                    return Err(ParseError::new(i + 1, column, token, "end of input"));
+                }
+                _ => first_line = Some(i),
+            }
+            values.push(parse_token(i + 1, column, token)?);
+        }
+    }
+    Ok(values)
 }
 
 pub fn as_lines(content: &str) -> Vec<String> {
@@ -32,74 +234,886 @@ pub fn as_lines(content: &str) -> Vec<String> {
 
 pub fn as_vs<T>(content: &str) -> Vec<Vec<T>>
 where
-    T: FromStr + std::fmt::Debug,
-    <T as FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    as_lines(content)
+    try_as_vs(content, Mode::Lenient).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Whitespace separated values per line; strict mode requires equally long lines.
+pub fn try_as_vs<T>(content: &str, mode: Mode) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+{
+    let lines: Vec<&str> = content.lines().collect();
+    let rows = lines
         .iter()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|p| {
-                    p.parse::<T>()
-                        .expect(&format!("cannot read {} as number", p))
-                })
-                .collect::<Vec<_>>()
+        .enumerate()
+        .map(|(i, line)| {
+            tokens(line)
+                .map(|(column, token)| parse_token(i + 1, column, token))
+                .collect()
         })
-        .collect()
+        .collect::<Result<Vec<Vec<T>>, _>>()?;
+    if mode == Mode::Strict {
+        if let Some(width) = rows.first().map(Vec::len) {
+            for (i, row) in rows.iter().enumerate() {
+                if row.len() != width {
+                    let extra = tokens(lines[i]).nth(width);
+                    let (column, token) = extra.unwrap_or((lines[i].chars().count() + 1, ""));
+                    return Err(ParseError::new(
+                        i + 1,
+                        column,
+                        token,
+                        format!("{} fields", width),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(rows)
 }
 
 pub fn as_digit_square(content: &str) -> Vec<Vec<u8>> {
-    as_lines(content)
+    try_as_digit_square(content, Mode::Lenient).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// One digit per cell; strict mode requires a rectangle.
+pub fn try_as_digit_square(content: &str, mode: Mode) -> Result<Vec<Vec<u8>>, ParseError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let rows = lines
         .iter()
-        .map(|line| {
+        .enumerate()
+        .map(|(i, line)| {
             line.chars()
-                .map(|p| p.to_digit(10).unwrap() as u8)
-                .collect::<Vec<_>>()
+                .enumerate()
+                .map(|(j, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as u8)
+                        .ok_or_else(|| ParseError::new(i + 1, j + 1, &c.to_string(), "a digit"))
+                })
+                .collect()
         })
-        .collect()
+        .collect::<Result<Vec<Vec<u8>>, _>>()?;
+    check_rows(&rows, &lines, mode)?;
+    Ok(rows)
 }
 
 pub fn as_as<T, const D: usize>(content: &str) -> Vec<[T; D]>
 where
-    T: FromStr + std::fmt::Debug,
-    <T as FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    as_lines(content)
-        .iter()
-        .map(|line| {
-            line.split_whitespace()
+    try_as_as(content, Mode::Lenient).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Exactly `D` values per line. Missing fields are always an error,
+/// extra fields only in strict mode.
+pub fn try_as_as<T, const D: usize>(content: &str, mode: Mode) -> Result<Vec<[T; D]>, ParseError>
+where
+    T: FromStr,
+{
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut fields = tokens(line);
+            let values = fields
+                .by_ref()
                 .take(D)
-                .map(|p| {
-                    p.parse::<T>()
-                        .expect(&format!("cannot read {} as number", p))
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap()
+                .map(|(column, token)| parse_token(i + 1, column, token))
+                .collect::<Result<Vec<T>, _>>()?;
+            let found = values.len();
+            let row: [T; D] = values.try_into().map_err(|_| {
+                let column = line.trim_end().chars().count() + 1;
+                ParseError::new(i + 1, column, "", format!("{} fields, got {}", D, found))
+            })?;
+            match fields.next() {
+                Some((column, token)) if mode == Mode::Strict => Err(ParseError::new(
+                    i + 1,
+                    column,
+                    token,
+                    format!("{} fields", D),
+                )),
+                _ => Ok(row),
+            }
         })
         .collect()
 }
 
-pub fn as_vecvec<'a, T>(content: &'a str) -> Vec<Vec<T>>
+/// A grid cell read from a single char of the input, see [`grid_cells!`]
+/// for enums of cells.
+pub trait FromGridChar: Sized {
+    fn from_grid_char(c: char) -> Option<Self>;
+
+    /// What an unknown glyph is reported as instead of.
+    fn expected() -> String {
+        std::any::type_name::<Self>().to_owned()
+    }
+}
+
+impl FromGridChar for char {
+    fn from_grid_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl FromGridChar for u8 {
+    fn from_grid_char(c: char) -> Option<Self> {
+        c.is_ascii().then_some(c as u8)
+    }
+
+    fn expected() -> String {
+        "an ASCII char".to_owned()
+    }
+}
+
+impl FromGridChar for Dir4 {
+    fn from_grid_char(c: char) -> Option<Self> {
+        Dir4::try_from(c).ok()
+    }
+
+    fn expected() -> String {
+        "a direction".to_owned()
+    }
+}
+
+/// Declares an enum of grid cells with one glyph per variant, parsed by
+/// [`as_grid`] and printed by `glyph` and `Display`:
+///
+/// ```ignore
+/// input::grid_cells! {
+///     enum Tile {
+///         Wall = '#',
+///         Floor = '.',
+///     }
+/// }
+/// ```
+macro_rules! grid_cells {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $glyph:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $name {
+            /// Glyphs of all variants, in declaration order.
+            #[allow(dead_code)]
+            pub const GLYPHS: &'static str = concat!($($glyph),+);
+
+            #[allow(dead_code)]
+            pub fn glyph(&self) -> char {
+                match self {
+                    $(Self::$variant => $glyph),+
+                }
+            }
+        }
+
+        impl $crate::input::FromGridChar for $name {
+            fn from_grid_char(c: char) -> Option<Self> {
+                match c {
+                    $($glyph => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn expected() -> String {
+                format!("one of {:?}", Self::GLYPHS)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.glyph())
+            }
+        }
+    };
+}
+pub(crate) use grid_cells;
+
+pub fn as_vecvec<T>(content: &str) -> Vec<Vec<T>>
+where
+    T: FromGridChar,
+{
+    try_as_vecvec(content, Mode::Lenient).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// One cell per char; strict mode requires a rectangle.
+pub fn try_as_vecvec<T>(content: &str, mode: Mode) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromGridChar,
+{
+    try_cells(content, mode, |line, column, c| {
+        T::from_grid_char(c)
+            .ok_or_else(|| ParseError::new(line, column, &c.to_string(), T::expected()))
+    })
+}
+
+/// One cell per char, built by `cell` from its 1-based line and column.
+fn try_cells<T>(
+    content: &str,
+    mode: Mode,
+    mut cell: impl FnMut(usize, usize, char) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut rows = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(j, c)| cell(i + 1, j + 1, c))
+            .collect::<Result<Vec<T>, _>>()?;
+        rows.push(row);
+    }
+    check_rows(&rows, &lines, mode)?;
+    Ok(rows)
+}
+
+pub fn as_grid<T>(content: &str) -> Grid<T>
+where
+    T: FromGridChar,
+{
+    try_as_grid(content).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// One cell per char; rows of different lengths are an error.
+pub fn try_as_grid<T>(content: &str) -> Result<Grid<T>, ParseError>
+where
+    T: FromGridChar,
+{
+    try_as_vecvec(content, Mode::Strict).map(Grid::from_rows)
+}
+
+/// A glyph taken out of a grid while parsing it, see
+/// [`try_as_grid_with_markers`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marker<T> {
+    pub glyph: char,
+    /// What the marker is replaced with in the grid.
+    pub cell: T,
+    /// Whether the glyph may appear any number of times instead of once.
+    pub many: bool,
+}
+
+impl<T> Marker<T> {
+    /// A glyph appearing exactly once, such as the start.
+    pub fn one(glyph: char, cell: T) -> Self {
+        Self {
+            glyph,
+            cell,
+            many: false,
+        }
+    }
+
+    /// A glyph appearing any number of times.
+    pub fn many(glyph: char, cell: T) -> Self {
+        Self {
+            glyph,
+            cell,
+            many: true,
+        }
+    }
+}
+
+/// Where the markers of a grid were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<Pos>>,
+}
+
+impl Markers {
+    /// The position of `glyph`, the first one for a marker appearing many
+    /// times. Panics if there is none.
+    pub fn pos(&self, glyph: char) -> Pos {
+        *self
+            .all(glyph)
+            .first()
+            .unwrap_or_else(|| panic!("no {:?} marker", glyph))
+    }
+
+    /// All positions of `glyph`, in reading order.
+    pub fn all(&self, glyph: char) -> &[Pos] {
+        self.positions.get(&glyph).map_or(&[], Vec::as_slice)
+    }
+}
+
+pub fn as_grid_with_markers<T>(content: &str, markers: &[Marker<T>]) -> (Grid<T>, Markers)
 where
-    T: na::Scalar,
-    char: TryInto<T>,
-    <char as TryInto<T>>::Error: core::fmt::Debug,
+    T: FromGridChar + Clone,
+{
+    try_as_grid_with_markers(content, markers).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Like [`try_as_grid`], taking the `markers` out of the grid. A marker
+/// expected once is an error when missing or repeated.
+pub fn try_as_grid_with_markers<T>(
+    content: &str,
+    markers: &[Marker<T>],
+) -> Result<(Grid<T>, Markers), ParseError>
+where
+    T: FromGridChar + Clone,
+{
+    let mut found = Markers::default();
+    let rows = try_cells(content, Mode::Strict, |line, column, c| {
+        let Some(marker) = markers.iter().find(|m| m.glyph == c) else {
+            return T::from_grid_char(c)
+                .ok_or_else(|| ParseError::new(line, column, &c.to_string(), T::expected()));
+        };
+        let positions = found.positions.entry(c).or_default();
+        if !marker.many && !positions.is_empty() {
+            let expected = format!("only one {:?}", c);
+            return Err(ParseError::new(line, column, &c.to_string(), expected));
+        }
+        positions.push(Pos::from((column - 1, line - 1)));
+        Ok(marker.cell.clone())
+    })?;
+    if let Some(missing) = markers
+        .iter()
+        .find(|m| !m.many && found.all(m.glyph).is_empty())
+    {
+        let line = content.lines().count() + 1;
+        let expected = format!("the {:?} marker", missing.glyph);
+        return Err(ParseError::new(line, 1, "", expected));
+    }
+    Ok((Grid::from_rows(rows), found))
+}
+
+/// The text matched by one `{}` of a template, see [`try_scan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl Field<'_> {
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        parse_token(self.line, self.column, self.text)
+    }
+}
+
+/// Values scanned from the fields of a template: tuples and arrays of
+/// [`FromStr`] types. Structs are built from these with `map`.
+pub trait Fields: Sized {
+    const COUNT: usize;
+
+    fn from_fields(fields: &[Field]) -> Result<Self, ParseError>;
+}
+
+macro_rules! fields_tuple {
+    ($n:literal: $($t:ident $i:tt),*) => {
+        impl<$($t: FromStr),*> Fields for ($($t,)*) {
+            const COUNT: usize = $n;
+
+            fn from_fields(fields: &[Field]) -> Result<Self, ParseError> {
+                Ok(($(fields[$i].parse::<$t>()?,)*))
+            }
+        }
+    };
+}
+
+fields_tuple!(1: A 0);
+fields_tuple!(2: A 0, B 1);
+fields_tuple!(3: A 0, B 1, C 2);
+fields_tuple!(4: A 0, B 1, C 2, D 3);
+fields_tuple!(5: A 0, B 1, C 2, D 3, E 4);
+fields_tuple!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+fields_tuple!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+fields_tuple!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+impl<T: FromStr, const N: usize> Fields for [T; N] {
+    const COUNT: usize = N;
+
+    fn from_fields(fields: &[Field]) -> Result<Self, ParseError> {
+        let values = fields
+            .iter()
+            .map(Field::parse)
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+/// 1-based line and column of byte `offset` into `content`.
+fn locate(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Matches `template` at byte `at` of `content`, returning the fields and
+/// where the match ends.
+///
+/// A field takes everything up to the text following it in the template,
+/// or up to the end of the line for a field at the end; it never spans lines.
+fn scan_at<'a>(
+    template: &str,
+    content: &'a str,
+    at: usize,
+) -> Result<(Vec<Field<'a>>, usize), ParseError> {
+    let error = |offset: usize, expected: String| {
+        let rest = &content[offset..];
+        let token = &rest[..rest.find('\n').unwrap_or(rest.len())];
+        let (line, column) = locate(content, offset);
+        ParseError::new(line, column, token, expected)
+    };
+
+    let mut literals = template.split("{}");
+    let first = literals.next().unwrap();
+    if !content[at..].starts_with(first) {
+        return Err(error(at, format!("{:?}", first)));
+    }
+    let mut pos = at + first.len();
+    let mut fields = Vec::new();
+    for literal in literals {
+        let rest = &content[pos..];
+        let line_end = rest.find('\n').unwrap_or(rest.len());
+        let len = if literal.is_empty() {
+            line_end
+        } else {
+            match rest.find(literal) {
+                Some(i) if i <= line_end => i,
+                _ => return Err(error(pos, format!("a value followed by {:?}", literal))),
+            }
+        };
+        let (line, column) = locate(content, pos);
+        fields.push(Field {
+            line,
+            column,
+            text: &rest[..len],
+        });
+        pos += len + literal.len();
+    }
+    Ok((fields, pos))
+}
+
+pub fn scan<T: Fields>(template: &str, content: &str) -> T {
+    try_scan(template, content).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Parses `content` laid out as `template`, with a typed value for every
+/// `{}` in it, e.g. `try_scan::<(i64, i64)>("X+{}, Y+{}", "X+94, Y+-34")`.
+///
+/// Leading and trailing whitespace of `content` is ignored.
+pub fn try_scan<T: Fields>(template: &str, content: &str) -> Result<T, ParseError> {
+    let (fields, end) = scan_template::<T>(
+        template,
+        content,
+        content.len() - content.trim_start().len(),
+    )?;
+    if !content[end..].trim().is_empty() {
+        let (line, column) = locate(content, end);
+        let rest = content[end..].trim_end();
+        return Err(ParseError::new(
+            line,
+            column,
+            rest.lines().next().unwrap_or(""),
+            "end of input",
+        ));
+    }
+    T::from_fields(&fields)
+}
+
+pub fn scan_all<T: Fields>(template: &str, content: &str) -> Vec<T> {
+    try_scan_all(template, content).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Parses `content` as `template` repeated any number of times, with
+/// whitespace such as blank lines between the repetitions.
+pub fn try_scan_all<T: Fields>(template: &str, content: &str) -> Result<Vec<T>, ParseError> {
+    let mut values = Vec::new();
+    let mut at = 0;
+    loop {
+        at += content[at..].len() - content[at..].trim_start().len();
+        if at == content.len() {
+            return Ok(values);
+        }
+        let (fields, end) = scan_template::<T>(template, content, at)?;
+        values.push(T::from_fields(&fields)?);
+        at = end;
+    }
+}
+
+fn scan_template<'a, T: Fields>(
+    template: &str,
+    content: &'a str,
+    at: usize,
+) -> Result<(Vec<Field<'a>>, usize), ParseError> {
+    assert_eq!(
+        template.matches("{}").count(),
+        T::COUNT,
+        "template {:?} does not have {} fields",
+        template,
+        T::COUNT
+    );
+    scan_at(template, content, at)
+}
+
+/// A blank line separated part of the input, see [`try_sections`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Name given by the caller, if any.
+    pub name: Option<&'a str>,
+    /// 0-based position among the sections.
+    pub index: usize,
+    /// 1-based line of the input the section starts on.
+    pub line: usize,
+    /// The lines of the section, without the trailing newline.
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn parse<T>(&self, parser: impl FnOnce(&'a str) -> Result<T, ParseError>) -> T {
+        self.try_parse(parser)
+            .unwrap_or_else(|e| panic!("{}: {}", self, e))
+    }
+
+    /// Parses the section on its own, with error lines counted from the
+    /// start of the whole input.
+    pub fn try_parse<T>(
+        &self,
+        parser: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parser(self.text).map_err(|e| ParseError {
+            line: e.line + self.line - 1,
+            ..e
+        })
+    }
+}
+
+impl std::fmt::Display for Section<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{} section", name),
+            None => write!(f, "section {}", self.index + 1),
+        }
+    }
+}
+
+/// All blank line separated sections of `content`, unnamed. Runs of blank
+/// lines count as one separator.
+pub fn split_sections(content: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for (i, line) in content.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((line, begin)) = start.take() {
+                sections.push(Section {
+                    name: None,
+                    index: sections.len(),
+                    line,
+                    text: &content[begin..end],
+                });
+            }
+        } else {
+            start.get_or_insert((i + 1, offset));
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+    if let Some((line, begin)) = start {
+        sections.push(Section {
+            name: None,
+            index: sections.len(),
+            line,
+            text: &content[begin..end],
+        });
+    }
+    sections
+}
+
+pub fn sections<'a, const N: usize>(content: &'a str, names: [&'a str; N]) -> [Section<'a>; N] {
+    try_sections(content, names).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Exactly `N` sections, named after `names`. A missing section or one too
+/// many is an error.
+pub fn try_sections<'a, const N: usize>(
+    content: &'a str,
+    names: [&'a str; N],
+) -> Result<[Section<'a>; N], ParseError> {
+    let mut sections = split_sections(content);
+    if let Some(extra) = sections.get(N) {
+        let token = extra.text.lines().next().unwrap_or("");
+        return Err(ParseError::new(extra.line, 1, token, "end of input"));
+    }
+    if sections.len() < N {
+        let line = content.lines().count() + 1;
+        let expected = format!("the {} section", names[sections.len()]);
+        return Err(ParseError::new(line, 1, "", expected));
+    }
+    for (section, name) in sections.iter_mut().zip(names) {
+        section.name = Some(name);
+    }
+    Ok(sections.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+pub fn as_separated<T>(content: &str, separator: &str) -> Vec<Vec<T>>
+where
+    T: FromStr,
+{
+    try_as_separated(content, separator).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Values per line split on `separator`, e.g. `75,47,61`.
+pub fn try_as_separated<T>(content: &str, separator: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
 {
     content
         .lines()
-        .map(|line| line.chars().map(|c| c.try_into().unwrap()).collect())
+        .enumerate()
+        .map(|(i, line)| {
+            let mut column = 1;
+            line.split(separator)
+                .map(|token| {
+                    let value = parse_token(i + 1, column, token);
+                    column += token.chars().count() + separator.chars().count();
+                    value
+                })
+                .collect()
+        })
         .collect()
 }
 
-pub fn as_matrix<'a, T>(content: &'a str) -> na::DMatrix<T>
+pub fn key_values<K, V>(content: &str, separator: &str) -> Vec<(K, V)>
 where
-    T: na::Scalar,
-    char: TryInto<T>,
-    <char as TryInto<T>>::Error: core::fmt::Debug,
+    K: FromStr,
+    V: FromStr,
 {
-    let vecs: Vec<Vec<T>> = as_vecvec::<T>(content);
-    let (m, n) = (vecs.len(), vecs[0].len());
-    let mat = na::DMatrix::from_vec(m, n, vecs.concat());
-    mat.transpose()
+    try_key_values(content, separator).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// One `key<separator>value` pair per line, in input order.
+pub fn try_key_values<K, V>(content: &str, separator: &str) -> Result<Vec<(K, V)>, ParseError>
+where
+    K: FromStr,
+    V: FromStr,
+{
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let Some((key, value)) = line.split_once(separator) else {
+                let column = line.chars().count() + 1;
+                return Err(ParseError::new(
+                    i + 1,
+                    column,
+                    "",
+                    format!("{:?}", separator),
+                ));
+            };
+            let column = key.chars().count() + separator.chars().count() + 1;
+            Ok((
+                parse_token(i + 1, 1, key)?,
+                parse_token(i + 1, column, value)?,
+            ))
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(as_as::<i32, 2>("1 2\n3   4"), vec![[1, 2], [3, 4]]);
+    assert_eq!(
+        try_as_as::<i32, 2>("1 2\n3 x", Mode::Lenient),
+        Err(ParseError::new(2, 3, "x", "i32"))
+    );
+    assert_eq!(
+        try_as_as::<i32, 2>("1 2\n3", Mode::Lenient),
+        Err(ParseError::new(2, 2, "", "2 fields, got 1"))
+    );
+    assert_eq!(
+        try_as_as::<i32, 2>("1 2 5", Mode::Lenient),
+        Ok(vec![[1, 2]])
+    );
+    assert_eq!(
+        try_as_as::<i32, 2>("1 2 5", Mode::Strict),
+        Err(ParseError::new(1, 5, "5", "2 fields"))
+    );
+    assert_eq!(
+        try_single_line_vec::<u64>("1 2\n 3", Mode::Strict),
+        Err(ParseError::new(2, 2, "3", "end of input"))
+    );
+    assert_eq!(
+        try_as_vs::<u8>("1 2\n3 4 5", Mode::Strict),
+        Err(ParseError::new(2, 5, "5", "2 fields"))
+    );
+    assert_eq!(
+        try_as_digit_square("12\n3", Mode::Strict),
+        Err(ParseError::new(2, 2, "", "a row of 2 cells"))
+    );
+    assert_eq!(
+        try_as_digit_square("12\n3a", Mode::Lenient),
+        Err(ParseError::new(2, 2, "a", "a digit"))
+    );
+    assert_eq!(
+        ParseError::new(2, 3, "x", "i32").to_string(),
+        "line 2, column 3: expected i32, found \"x\""
+    );
+}
+
+#[test]
+fn test_sources() {
+    assert_eq!(Source::from_arg(6, "-"), Source::Stdin);
+    assert_eq!(
+        Source::from_arg(6, "./day6.txt"),
+        Source::Path("./day6.txt".into())
+    );
+    assert_eq!(
+        Source::from_arg(6, "example1"),
+        Source::Named {
+            day: 6,
+            name: "example1".to_owned()
+        }
+    );
+    assert_eq!(Source::day(6).path(), Some(root().join("day6")));
+    assert_eq!(
+        Source::from_arg(6, "alt").path(),
+        Some(root().join("day6.alt"))
+    );
+    assert!(read(&Source::from_arg(6, "no-such-input")).is_err());
+}
+
+#[test]
+fn test_scan() {
+    let (x, y): (i64, i64) = scan("Button A: X+{}, Y+{}", "Button A: X+94, Y+-34\n");
+    assert_eq!((x, y), (94, -34));
+    assert_eq!(
+        scan::<(String, u8)>("{} is {} years", "Ada is 36 years"),
+        ("Ada".to_owned(), 36)
+    );
+
+    let robots: Vec<[i64; 4]> = scan_all("p={},{} v={},{}", "p=0,4 v=3,-3\np=6,3 v=-1,-3\n");
+    assert_eq!(robots, vec![[0, 4, 3, -3], [6, 3, -1, -3]]);
+
+    let machines = "A: X+94, Y+34
+Prize: X=8400, Y=5400
+
+A: X+26, Y+66
+Prize: X=12748, Y=12176
+";
+    let template = "A: X+{}, Y+{}\nPrize: X={}, Y={}";
+    let parsed: Vec<(u32, u32, u64, u64)> = scan_all(template, machines);
+    assert_eq!(parsed[1], (26, 66, 12748, 12176));
+
+    let program: (u64, String) = scan("A: {}\n\nProgram: {}", "A: 729\n\nProgram: 0,1,5\n");
+    assert_eq!(program.1, "0,1,5");
+
+    let err = try_scan_all::<(u32, u32, u64, u64)>(template, &machines.replace("Y=12176", "Y=-1"))
+        .unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (5, 19, "-1"));
+    let err = try_scan::<(i64, i64)>("X+{}, Y+{}", "X+1; Y+2").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"line 1, column 3: expected a value followed by ", Y+", found "1; Y+2""#
+    );
+    let err = try_scan::<(i64,)>("X+{}", "Y+1").unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (1, r#""X+""#));
+    assert!(try_scan::<(i64,)>("X+{}", "X+1\nX+2").is_err());
+}
+
+#[test]
+fn test_sections() {
+    let content = "47|53\n97|13\n\n\n75,47,61\n97,13\n";
+    let [rules, pages] = sections(content, ["rules", "pages"]);
+    assert_eq!((rules.line, rules.text), (1, "47|53\n97|13"));
+    assert_eq!((pages.index, pages.line), (1, 5));
+    let rules: Vec<(u32, u32)> = rules.parse(|s| try_scan_all("{}|{}", s));
+    assert_eq!(rules, vec![(47, 53), (97, 13)]);
+    assert_eq!(
+        pages.parse(|s| try_as_separated::<u32>(s, ",")),
+        vec![vec![75, 47, 61], vec![97, 13]]
+    );
+    assert_eq!(
+        pages.try_parse(|s| try_as_separated::<u8>(s, ",")),
+        Ok(vec![vec![75, 47, 61], vec![97, 13]])
+    );
+    assert_eq!(
+        pages.try_parse(|s| try_as_separated::<u8>(&s.replace("13", "x"), ",")),
+        Err(ParseError::new(6, 4, "x", "u8"))
+    );
+
+    let split = split_sections("\nx00: 1\ny00: 0\n\nx00 AND y00 -> z00");
+    assert_eq!(split.len(), 2);
+    assert_eq!(split[1].to_string(), "section 2");
+    let wires: Vec<(String, u8)> = split[0].parse(|s| try_key_values(s, ": "));
+    assert_eq!(wires[1], ("y00".to_owned(), 0));
+    assert_eq!(
+        split[1].try_parse(|s| try_key_values::<String, u8>(s, ": ")),
+        Err(ParseError::new(5, 19, "", "\": \""))
+    );
+
+    assert_eq!(
+        try_sections("r, wr\n", ["patterns", "towels"]),
+        Err(ParseError::new(2, 1, "", "the towels section"))
+    );
+    assert_eq!(
+        try_sections("#.\n\n<>\n\nextra", ["map", "moves"]),
+        Err(ParseError::new(5, 1, "extra", "end of input"))
+    );
+}
+
+#[test]
+fn test_grid_cells() {
+    grid_cells! {
+        enum Tile {
+            Wall = '#',
+            Floor = '.',
+            Start = 'S',
+        }
+    }
+
+    let grid: Grid<Tile> = as_grid("#S\n.#");
+    assert_eq!(grid[(1usize, 0)], Tile::Start);
+    assert_eq!(grid[(0usize, 1)], Tile::Floor);
+    assert_eq!(crate::render::text(&grid, Tile::glyph), "#S\n.#\n");
+    assert_eq!(Tile::Wall.to_string(), "#");
+    assert_eq!(
+        try_as_grid::<Tile>("#S\n.E"),
+        Err(ParseError::new(2, 2, "E", "one of \"#.S\""))
+    );
+    assert_eq!(
+        try_as_grid::<Dir4>(">v\n<^"),
+        Ok(Grid::from_rows([
+            vec![Dir4::East, Dir4::South],
+            vec![Dir4::West, Dir4::North]
+        ]))
+    );
+    assert!(try_as_grid::<u8>("é").is_err());
+}
+
+#[test]
+fn test_markers() {
+    let maze = "#S.O\n#.OE";
+    let markers = [
+        Marker::one('S', '.'),
+        Marker::one('E', '.'),
+        Marker::many('O', '#'),
+    ];
+    let (grid, found) = as_grid_with_markers(maze, &markers);
+    assert_eq!(crate::render::text(&grid, |c| *c), "#..#\n#.#.\n");
+    assert_eq!(found.pos('S'), Pos::new(1, 0));
+    assert_eq!(found.pos('E'), Pos::new(3, 1));
+    assert_eq!(found.all('O'), &[Pos::new(3, 0), Pos::new(2, 1)]);
+    assert_eq!(found.all('X'), &[]);
+
+    assert_eq!(
+        try_as_grid_with_markers(&maze.replace('E', "S"), &markers),
+        Err(ParseError::new(2, 4, "S", "only one 'S'"))
+    );
+    assert_eq!(
+        try_as_grid_with_markers(&maze.replace('E', "."), &markers),
+        Err(ParseError::new(3, 1, "", "the 'E' marker"))
+    );
+    let (grid, _) = as_grid_with_markers(maze, &markers[..2]);
+    assert_eq!(grid[(3usize, 0)], 'O');
 }
@@ -1,48 +1,34 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod examples;
 pub mod input;
+pub mod render;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
 pub mod square;
+pub mod submit;
 
-#[allow(dead_code)]
-pub fn solve1<T, U>(day: &str, input_func: impl Fn(&str) -> T, part1: impl Fn(&T) -> U)
-where
-    U: core::fmt::Display,
-{
-    let t: T = input_func(&input::read(day));
-    println!("part1: {}", part1(&t));
-}
+pub use answer::Answer;
+pub use solution::Solution;
 
-#[allow(dead_code)]
-pub fn test1<'a, T, U>(inp: &str, want: U, input_func: impl Fn(&str) -> T, part1: impl Fn(&T) -> U)
-where
-    U: 'a + core::fmt::Debug + core::fmt::Debug + PartialEq,
-{
-    let t: T = input_func(inp);
-    assert_eq!(part1(&t), want);
+/// Selects which parts of a day are run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    Only(u8),
 }
 
-#[allow(dead_code)]
-pub fn solve<T, U>(
-    day: &str,
-    input_func: impl Fn(&str) -> T,
-    part1: impl Fn(&T) -> U,
-    part2: impl Fn(&T) -> U,
-) where
-    U: core::fmt::Display,
-{
-    let t: T = input_func(&input::read(day));
-    let start = std::time::Instant::now();
-    let soln1 = part1(&t);
-    println!(
-        "part1 in {:.5} seconds: {}",
-        start.elapsed().as_secs_f32(),
-        soln1
-    );
-    let start = std::time::Instant::now();
-    let soln2 = part2(&t);
-    println!(
-        "part2 in {:.5} seconds: {}",
-        start.elapsed().as_secs_f32(),
-        soln2
-    );
+impl Parts {
+    pub fn includes(&self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Only(p) => *p == part,
+        }
+    }
 }
 
 pub fn digits(num: u64) -> u32 {
@@ -0,0 +1,214 @@
+use std::{collections::HashMap, fmt::Write};
+
+use crate::square::{Grid, Pos};
+
+/// A 24 bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(230, 200, 40);
+}
+
+/// Cells drawn over a grid, such as a path or the visited cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overlay {
+    cells: HashMap<Pos, Option<char>>,
+    colour: Rgb,
+}
+
+impl Overlay {
+    /// Highlights `cells` in `colour`, keeping their glyphs.
+    pub fn new(cells: impl IntoIterator<Item = Pos>, colour: Rgb) -> Self {
+        Self {
+            cells: cells.into_iter().map(|p| (p, None)).collect(),
+            colour,
+        }
+    }
+
+    /// Highlights `cells` in `colour`, drawing each with its own glyph.
+    pub fn with_glyphs(cells: impl IntoIterator<Item = (Pos, char)>, colour: Rgb) -> Self {
+        Self {
+            cells: cells.into_iter().map(|(p, c)| (p, Some(c))).collect(),
+            colour,
+        }
+    }
+
+    /// Draws every cell with `glyph`.
+    pub fn glyph(mut self, glyph: char) -> Self {
+        self.cells.values_mut().for_each(|c| *c = Some(glyph));
+        self
+    }
+}
+
+/// The topmost overlay covering `pos`.
+fn overlay_at(overlays: &[Overlay], pos: Pos) -> Option<(Rgb, Option<char>)> {
+    overlays
+        .iter()
+        .rev()
+        .find_map(|o| o.cells.get(&pos).map(|c| (o.colour, *c)))
+}
+
+/// Counts the points on each cell of a `width` by `height` grid, dropping
+/// points outside it.
+pub fn plot(width: usize, height: usize, points: impl IntoIterator<Item = Pos>) -> Grid<usize> {
+    let mut grid = Grid::new(width, height, 0);
+    for p in points {
+        if let Some(n) = grid.get_mut(p) {
+            *n += 1;
+        }
+    }
+    grid
+}
+
+/// One line of glyphs per row.
+pub fn text<T>(grid: &Grid<T>, glyph: impl Fn(&T) -> char) -> String {
+    grid.rows()
+        .map(|row| row.iter().map(&glyph).chain(['\n']).collect::<String>())
+        .collect()
+}
+
+/// Like [`text`], with overlay cells coloured for a terminal; later
+/// overlays are drawn over earlier ones.
+pub fn ansi<T>(grid: &Grid<T>, glyph: impl Fn(&T) -> char, overlays: &[Overlay]) -> String {
+    let mut s = String::new();
+    for pos in grid.positions() {
+        let plain = glyph(&grid[pos]);
+        match overlay_at(overlays, pos) {
+            Some((Rgb(r, g, b), c)) => write!(
+                s,
+                "\x1b[1;38;2;{};{};{}m{}\x1b[0m",
+                r,
+                g,
+                b,
+                c.unwrap_or(plain)
+            )
+            .unwrap(),
+            None => s.push(plain),
+        }
+        if pos.x as usize + 1 == grid.width() {
+            s.push('\n');
+        }
+    }
+    s
+}
+
+/// RGB bytes of the grid drawn with `scale` by `scale` pixels per cell.
+fn pixels<T>(
+    grid: &Grid<T>,
+    colour: impl Fn(&T) -> Rgb,
+    overlays: &[Overlay],
+    scale: usize,
+) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(grid.width() * grid.height() * scale * scale * 3);
+    for (y, cells) in grid.rows().enumerate() {
+        let line: Vec<u8> = cells
+            .iter()
+            .enumerate()
+            .flat_map(|(x, cell)| {
+                let Rgb(r, g, b) = overlay_at(overlays, Pos::from((x, y)))
+                    .map_or_else(|| colour(cell), |(c, _)| c);
+                [r, g, b].repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            bytes.extend(&line);
+        }
+    }
+    bytes
+}
+
+/// A binary PPM image with `scale` by `scale` pixels per cell.
+pub fn ppm<T>(
+    grid: &Grid<T>,
+    colour: impl Fn(&T) -> Rgb,
+    overlays: &[Overlay],
+    scale: usize,
+) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        grid.width() * scale,
+        grid.height() * scale
+    )
+    .into_bytes();
+    image.extend(pixels(grid, colour, overlays, scale));
+    image
+}
+
+/// A PNG image with `scale` by `scale` pixels per cell.
+pub fn png<T>(
+    grid: &Grid<T>,
+    colour: impl Fn(&T) -> Rgb,
+    overlays: &[Overlay],
+    scale: usize,
+) -> Vec<u8> {
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(
+        &mut image,
+        (grid.width() * scale) as u32,
+        (grid.height() * scale) as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    // Writing to memory cannot fail.
+    let mut writer = encoder.write_header().unwrap();
+    writer
+        .write_image_data(&pixels(grid, colour, overlays, scale))
+        .unwrap();
+    writer.finish().unwrap();
+    image
+}
+
+#[allow(dead_code)]
+const TEST_GRID: &str = "#..
+.#.";
+
+#[test]
+fn test_text() {
+    let grid = crate::input::as_grid::<char>(TEST_GRID);
+    assert_eq!(text(&grid, |c| *c), "#..\n.#.\n");
+
+    let path = Overlay::new([Pos::new(1, 0), Pos::new(2, 0)], Rgb::RED).glyph('o');
+    let guard = Overlay::with_glyphs([(Pos::new(2, 0), '>')], Rgb::GREEN);
+    assert_eq!(
+        ansi(&grid, |c| *c, &[path, guard]),
+        "#\x1b[1;38;2;220;50;47mo\x1b[0m\x1b[1;38;2;80;200;80m>\x1b[0m\n.#.\n"
+    );
+
+    let robots = plot(3, 2, [Pos::new(0, 1), Pos::new(0, 1), Pos::new(5, 5)]);
+    assert_eq!(
+        text(&robots, |n| if *n > 0 { '#' } else { '.' }),
+        "...\n#..\n"
+    );
+    assert_eq!(robots[(0usize, 1)], 2);
+}
+
+#[test]
+fn test_images() {
+    let grid = crate::input::as_grid::<char>(TEST_GRID);
+    let colour = |c: &char| if *c == '#' { Rgb::WHITE } else { Rgb::BLACK };
+    let visited = [Overlay::new([Pos::new(0, 1)], Rgb::BLUE)];
+
+    let image = ppm(&grid, colour, &visited, 2);
+    let (header, data) = image.split_at(11);
+    assert_eq!(header, b"P6\n6 4\n255\n");
+    assert_eq!(data.len(), 6 * 4 * 3);
+    assert_eq!(&data[..6], &[255; 6]);
+    // Second pixel row, still the first cell row.
+    assert_eq!(&data[18..24], &[255; 6]);
+    assert_eq!(&data[36..39], &[38, 139, 210]);
+
+    let image = png(&grid, colour, &visited, 2);
+    let decoder = png::Decoder::new(image.as_slice());
+    let mut reader = decoder.read_info().unwrap();
+    let mut decoded = vec![0; reader.output_buffer_size()];
+    reader.next_frame(&mut decoded).unwrap();
+    assert_eq!((reader.info().width, reader.info().height), (6, 4));
+    assert_eq!(decoded, data);
+}
@@ -0,0 +1,201 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Template new days are rendered from, `%DAY%` is replaced by the day number.
+pub const TEMPLATE: &str = include_str!("../day.rs.tmpl");
+
+pub fn render(template: &str, day: u8) -> String {
+    template.replace("%DAY%", &day.to_string())
+}
+
+/// Adds `day` to the `days!` registry in `registry`, keeping it sorted.
+/// Returns `None` if the day is already registered.
+pub fn register(registry: &str, day: u8) -> Result<Option<String>, String> {
+    let entry = format!("    {} => day{}::Day{},", day, day, day);
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| *l == "days! {")
+        .ok_or("no days! registry found")?;
+    let len = lines[start..]
+        .iter()
+        .position(|l| *l == "}")
+        .ok_or("days! registry is not closed")?;
+    let entries = start + 1..start + len;
+    if lines[entries.clone()].contains(&entry.as_str()) {
+        return Ok(None);
+    }
+    let at = lines[entries.clone()]
+        .iter()
+        .position(|l| {
+            l.split_whitespace()
+                .next()
+                .and_then(|n| n.parse::<u8>().ok())
+                .is_some_and(|n| n > day)
+        })
+        .map_or(entries.end, |i| entries.start + i);
+    lines.insert(at, &entry);
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// The first `<pre><code>` block of a puzzle page, with markup removed.
+pub fn extract_example(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let len = html[start..].find("</code></pre>")?;
+    Some(unescape(&strip_tags(&html[start..start + len])))
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Contents of a new example file: no answers yet, then the example input.
+pub fn example_file(example: &str) -> String {
+    format!("\n{}\n", example.trim_end_matches('\n'))
+}
+
+/// Files touched by [`new_day`].
+#[derive(Debug, Default)]
+pub struct Created {
+    pub files: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Scaffolds `day` inside the crate at `root`: the solution module from
+/// [`TEMPLATE`], its registry entry, an example taken from `page` and the
+/// puzzle input. Existing files are left alone.
+pub fn new_day(
+    root: &Path,
+    day: u8,
+    page: Option<&str>,
+    puzzle_input: Option<&str>,
+) -> io::Result<Created> {
+    let mut created = Created::default();
+    let mut write = |path: PathBuf, content: &str| -> io::Result<()> {
+        if path.exists() {
+            created.skipped.push(path);
+        } else {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, content)?;
+            created.files.push(path);
+        }
+        Ok(())
+    };
+
+    let days = root.join("src").join("days");
+    write(days.join(format!("day{}.rs", day)), &render(TEMPLATE, day))?;
+    if let Some(example) = page.and_then(extract_example) {
+        let dir = root.join("examples").join(format!("day{}", day));
+        write(dir.join("example"), &example_file(&example))?;
+    }
+    if let Some(content) = puzzle_input {
+        let path = root.join("inputs").join(format!("day{}", day));
+        write(path, content)?;
+    }
+
+    let registry = days.join("mod.rs");
+    let content = fs::read_to_string(&registry)?;
+    match register(&content, day).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))? {
+        Some(updated) => {
+            fs::write(&registry, updated)?;
+            created.files.push(registry);
+        }
+        None => created.skipped.push(registry),
+    }
+    Ok(created)
+}
+
+/// The crate [`new_day`] scaffolds into by default.
+pub fn crate_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn test_extract_example() {
+    let day1 = extract_example(include_str!("../fixtures/day1.html"));
+    assert_eq!(
+        day1.as_deref(),
+        Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+    );
+
+    let day15 = extract_example(include_str!("../fixtures/day15.html")).unwrap();
+    assert!(day15.starts_with("########\n#..O.O.#\n##@.O..#\n"));
+    assert!(day15.ends_with("\n<^^>>>vv<v>>v<<\n"));
+    assert_eq!(extract_example("<p>no example</p>"), None);
+}
+
+#[test]
+fn test_register() {
+    let registry = "days! {\n    1 => day1::Day1,\n    3 => day3::Day3,\n}\n\npub fn get() {\n}\n";
+    let updated = register(registry, 2).unwrap().unwrap();
+    assert_eq!(
+        updated,
+        "days! {\n    1 => day1::Day1,\n    2 => day2::Day2,\n    3 => day3::Day3,\n}\n\npub fn get() {\n}\n"
+    );
+    assert_eq!(register(&updated, 2), Ok(None));
+    assert!(register(registry, 25)
+        .unwrap()
+        .unwrap()
+        .contains("    3 => day3::Day3,\n    25 => day25::Day25,\n}"));
+    assert!(register("fn main() {}", 2).is_err());
+}
+
+#[test]
+fn test_new_day() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let days = root.join("src").join("days");
+    fs::create_dir_all(&days).unwrap();
+    fs::write(days.join("mod.rs"), "days! {\n    1 => day1::Day1,\n}\n").unwrap();
+
+    let page = include_str!("../fixtures/day1.html");
+    let created = new_day(&root, 2, Some(page), Some("1 2\n")).unwrap();
+    assert_eq!(created.files.len(), 4);
+    assert_eq!(
+        fs::read_to_string(root.join("examples/day2/example")).unwrap(),
+        "\n3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"
+    );
+    assert!(fs::read_to_string(days.join("mod.rs"))
+        .unwrap()
+        .contains("    2 => day2::Day2,"));
+
+    let again = new_day(&root, 2, Some(page), None).unwrap();
+    assert!(again.files.is_empty());
+    assert_eq!(again.skipped.len(), 3);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_render() {
+    let rendered = render(TEMPLATE, 25);
+    assert!(rendered.contains("pub struct Day25;"));
+    assert!(!rendered.contains("%DAY%"));
+    assert_eq!(example_file("1 2\n3 4\n"), "\n1 2\n3 4\n");
+    assert_eq!(
+        crate::examples::Example::parse("example", &example_file("1 2"))
+            .unwrap()
+            .input,
+        "1 2\n"
+    );
+}
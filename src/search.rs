@@ -0,0 +1,391 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search from a single start state.
+///
+/// Holds the cost of every settled state and the predecessors it was reached
+/// from. A search stopped at a goal only settles states up to the goal.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    start: S,
+    dist: HashMap<S, C>,
+    /// Predecessors on cheapest paths; only the first one found unless
+    /// searching with [`dijkstra_all`].
+    prev: HashMap<S, Vec<S>>,
+    /// Settled states, each after all of its predecessors.
+    order: Vec<S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S) -> Self {
+        Self {
+            start,
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            order: Vec::new(),
+            goal: None,
+        }
+    }
+
+    fn settle(&mut self, state: S, cost: C, prev: Vec<S>) {
+        self.dist.insert(state.clone(), cost);
+        if !prev.is_empty() {
+            self.prev.insert(state.clone(), prev);
+        }
+        self.order.push(state);
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.dist.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.dist
+    }
+
+    pub fn into_distances(self) -> HashMap<S, C> {
+        self.dist
+    }
+
+    /// The first goal state reached, if any.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// States from the start to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.dist.get(target)?;
+        let mut path = vec![target.clone()];
+        while *path.last().unwrap() != self.start {
+            path.push(self.prev[path.last().unwrap()][0].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// States from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// States `state` is reached from on a cheapest path.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.prev.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The predecessors of every reached state but the start.
+    pub fn dag(&self) -> &HashMap<S, Vec<S>> {
+        &self.prev
+    }
+
+    /// Number of distinct cheapest paths from the start to `target`.
+    ///
+    /// Counts only the recorded predecessors, so searches other than
+    /// [`dijkstra_all`] find at most one path.
+    pub fn count_paths_to(&self, target: &S) -> u64 {
+        let mut counts: HashMap<&S, u64> = HashMap::new();
+        for state in &self.order {
+            let count = if *state == self.start {
+                1
+            } else {
+                self.predecessors(state).iter().map(|p| counts[p]).sum()
+            };
+            if state == target {
+                return count;
+            }
+            counts.insert(state, count);
+        }
+        0
+    }
+
+    /// Every state on some cheapest path from the start to `target`.
+    pub fn states_on_paths_to(&self, target: &S) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        if !self.dist.contains_key(target) {
+            return seen;
+        }
+        let mut stack = vec![target.clone()];
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth first search where every step costs one.
+///
+/// Stops at the first state accepted by `is_goal`; pass `|_| false` to
+/// explore everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    search.settle(start.clone(), 0, Vec::new());
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.dist[&state] + 1;
+        for next in neighbours(&state) {
+            if search.dist.contains_key(&next) {
+                continue;
+            }
+            search.settle(next.clone(), cost, vec![state.clone()]);
+            queue.push_back(next);
+        }
+    }
+    search
+}
+
+/// Cheapest paths where `neighbours` yields `(cost, state)` steps.
+///
+/// Costs must not be negative; `C::default()` is the cost of the start.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (C, S)>,
+{
+    explore(start, neighbours, |_| C::default(), is_goal, false)
+}
+
+/// [`dijkstra`] recording every predecessor on a cheapest path, so the
+/// result holds the DAG of all cheapest paths.
+///
+/// Steps must cost more than zero.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (C, S)>,
+{
+    explore(start, neighbours, |_| C::default(), is_goal, true)
+}
+
+/// [`dijkstra`] guided towards the goal by `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost and must be
+/// consistent, as the manhattan distance is on a grid.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (C, S)>,
+{
+    explore(start, neighbours, heuristic, is_goal, false)
+}
+
+fn explore<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all_predecessors: bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (C, S)>,
+{
+    let mut search = Search::new(start.clone());
+    // Cheapest known cost and predecessors of states not settled yet.
+    let mut best: HashMap<S, (C, Vec<S>)> =
+        HashMap::from([(start.clone(), (C::default(), Vec::new()))]);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if search.dist.contains_key(&state) || best[&state].0 < cost {
+            continue;
+        }
+        let (_, prev) = best.remove(&state).unwrap();
+        search.settle(state.clone(), cost, prev);
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (step, next) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search.dist.contains_key(&next) {
+                continue;
+            }
+            match best.get_mut(&next) {
+                Some((c, prev)) if *c == next_cost => {
+                    if all_predecessors {
+                        prev.push(state.clone());
+                    }
+                    continue;
+                }
+                Some((c, _)) if *c < next_cost => continue,
+                _ => {}
+            }
+            best.insert(next.clone(), (next_cost, vec![state.clone()]));
+            heap.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    search
+}
+
+/// Heap entry ordered so the lowest priority is popped first.
+struct Entry<C, S> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<C: Ord, S> Ord for Entry<C, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<C: Ord, S> PartialOrd for Entry<C, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord, S> PartialEq for Entry<C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<C: Ord, S> Eq for Entry<C, S> {}
+
+#[allow(dead_code)]
+const TEST_MAZE: &str = "S..#
+.#.#
+.#..
+...E";
+
+#[test]
+fn test_bfs() {
+    use crate::square::{straight_neighbours, Pos};
+
+    let maze = crate::input::as_grid::<char>(TEST_MAZE);
+    let open = |p: &Pos| straight_neighbours(&maze, *p, |_, c| *c != '#').map(|(n, _)| n);
+    let end = Pos::new(3, 3);
+
+    let search = bfs(Pos::ZERO, open, |p| *p == end);
+    assert_eq!(search.goal_cost(), Some(6));
+    let path = search.path().unwrap();
+    assert_eq!((path[0], path[6]), (Pos::ZERO, end));
+    assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+    let all = bfs(Pos::ZERO, open, |_| false);
+    assert_eq!(all.goal(), None);
+    assert_eq!(all.distances().len(), 12);
+    assert_eq!(all.distance(&Pos::new(3, 2)), Some(5));
+    assert_eq!(all.path_to(&Pos::new(3, 0)), None);
+}
+
+#[test]
+fn test_dijkstra() {
+    // Going round through c is cheaper than the direct edge.
+    let edges = HashMap::from([
+        ('a', vec![(10, 'd'), (1, 'b')]),
+        ('b', vec![(2, 'c')]),
+        ('c', vec![(3, 'd')]),
+        ('d', vec![(1, 'e')]),
+    ]);
+    let next = |s: &char| edges.get(s).cloned().unwrap_or_default();
+    let search = dijkstra('a', next, |s| *s == 'd');
+    assert_eq!(search.goal_cost(), Some(6));
+    assert_eq!(search.path(), Some(vec!['a', 'b', 'c', 'd']));
+    assert_eq!(search.distance(&'e'), None);
+
+    let search = dijkstra('a', next, |_| false);
+    assert_eq!(search.distance(&'e'), Some(7u32));
+}
+
+#[test]
+fn test_astar() {
+    use crate::square::{straight_neighbours, Pos};
+
+    let maze = crate::input::as_grid::<char>(TEST_MAZE);
+    let end = Pos::new(3, 3);
+    let search = astar(
+        Pos::ZERO,
+        |p| straight_neighbours(&maze, *p, |_, c| *c != '#').map(|(n, _)| (1, n)),
+        |p| p.manhattan(end),
+        |p| *p == end,
+    );
+    assert_eq!(search.goal_cost(), Some(6));
+    assert_eq!(search.path().map(|p| p.len()), Some(7));
+}
+
+#[test]
+fn test_all_paths() {
+    use crate::square::{straight_neighbours, Pos};
+
+    // Every monotone path through an open 3x3 grid is a cheapest one.
+    let grid = crate::input::as_grid::<char>("...\n...\n...");
+    let end = Pos::new(2, 2);
+    let search = dijkstra_all(
+        Pos::ZERO,
+        |p| straight_neighbours(&grid, *p, |_, _| true).map(|(n, _)| (1, n)),
+        |p| *p == end,
+    );
+    assert_eq!(search.goal_cost(), Some(4));
+    assert_eq!(search.count_paths_to(&end), 6);
+    assert_eq!(search.count_paths_to(&Pos::new(1, 1)), 2);
+    assert_eq!(search.predecessors(&end).len(), 2);
+    assert_eq!(search.states_on_paths_to(&end).len(), 9);
+    assert_eq!(search.states_on_paths_to(&Pos::new(0, 2)).len(), 3);
+
+    // The detour through c costs more, so it is not part of the DAG.
+    let edges = HashMap::from([
+        ('a', vec![(1, 'b'), (2, 'c'), (1, 'd')]),
+        ('b', vec![(2, 'e')]),
+        ('c', vec![(2, 'e')]),
+        ('d', vec![(2, 'e')]),
+    ]);
+    let next = |s: &char| edges.get(s).cloned().unwrap_or_default();
+    let search = dijkstra_all('a', next, |_| false);
+    assert_eq!(search.count_paths_to(&'e'), 2);
+    assert_eq!(
+        search.states_on_paths_to(&'e'),
+        HashSet::from(['a', 'b', 'd', 'e'])
+    );
+    assert_eq!(search.dag().len(), 4);
+    assert_eq!(dijkstra('a', next, |_| false).count_paths_to(&'e'), 1);
+}
@@ -0,0 +1,83 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    bench::{self, BenchConfig, Stats},
+    Answer, Parts,
+};
+
+/// A day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// A picture of the solution, shown by `aoc run --render`.
+    fn render(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// The answer to one part and how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Object safe view of a [`Solution`], so days with different input types
+/// can live in the same registry.
+pub trait Runner {
+    fn solve(&self, input: &str, parts: Parts) -> Vec<Solved>;
+
+    /// Times parsing and the selected parts separately, labelled `parse`, `part1` and `part2`.
+    fn bench(&self, input: &str, parts: Parts, config: &BenchConfig) -> Vec<(&'static str, Stats)>;
+
+    fn render(&self, input: &str) -> Option<String>;
+}
+
+impl<S: Solution> Runner for S {
+    fn solve(&self, input: &str, parts: Parts) -> Vec<Solved> {
+        let t = S::parse(input);
+        let mut solved = Vec::new();
+        if parts.includes(1) {
+            let start = Instant::now();
+            let answer = S::part1(&t).into();
+            solved.push(Solved {
+                part: 1,
+                answer,
+                elapsed: start.elapsed(),
+            });
+        }
+        if parts.includes(2) {
+            let start = Instant::now();
+            let answer = S::part2(&t).into();
+            solved.push(Solved {
+                part: 2,
+                answer,
+                elapsed: start.elapsed(),
+            });
+        }
+        solved
+    }
+
+    fn bench(&self, input: &str, parts: Parts, config: &BenchConfig) -> Vec<(&'static str, Stats)> {
+        let mut stats = vec![("parse", bench::measure(config, || S::parse(input)))];
+        let t = S::parse(input);
+        if parts.includes(1) {
+            stats.push(("part1", bench::measure(config, || S::part1(&t))));
+        }
+        if parts.includes(2) {
+            stats.push(("part2", bench::measure(config, || S::part2(&t))));
+        }
+        stats
+    }
+
+    fn render(&self, input: &str) -> Option<String> {
+        S::render(&S::parse(input))
+    }
+}
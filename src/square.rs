@@ -1,100 +1,1037 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
 
-pub type Pos = (isize, isize);
+use nalgebra::Vector2;
 
-pub type M<T> = Vec<Vec<T>>;
+/// A position on a grid, `x` growing east and `y` growing south.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
 
-pub fn straight_neighbours<'a, T>(
-    square: &'a M<T>,
-    (x, y): Pos,
+/// The offset between two positions.
+pub type Vec2 = Pos;
+
+impl Pos {
+    pub const ZERO: Pos = Pos::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Pos) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Pos) -> isize {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Rotates by 90° clockwise as seen on screen, turning east into south.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90° counterclockwise as seen on screen, turning east into north.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, other: Pos) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, other: Pos) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Pos {
+    type Output = Pos;
+
+    fn mul(self, k: isize) -> Pos {
+        Pos::new(self.x * k, self.y * k)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Pos {
+        Pos::new(-self.x, -self.y)
+    }
+}
+
+impl From<(isize, isize)> for Pos {
+    fn from((x, y): (isize, isize)) -> Self {
+        Pos::new(x, y)
+    }
+}
+
+impl From<Pos> for (isize, isize) {
+    fn from(p: Pos) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Self {
+        Pos::new(x as isize, y as isize)
+    }
+}
+
+impl TryFrom<Pos> for (usize, usize) {
+    type Error = std::num::TryFromIntError;
+
+    /// Fails for positions left of or above the origin.
+    fn try_from(p: Pos) -> Result<Self, Self::Error> {
+        Ok((p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl From<Vector2<isize>> for Pos {
+    fn from(v: Vector2<isize>) -> Self {
+        Pos::new(v.x, v.y)
+    }
+}
+
+impl From<Pos> for Vector2<isize> {
+    fn from(p: Pos) -> Self {
+        Vector2::new(p.x, p.y)
+    }
+}
+
+/// One of the four straight directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    pub fn iter() -> impl Iterator<Item = Dir4> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::East | Dir4::West)
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir4::North => Pos::new(0, -1),
+            Dir4::East => Pos::new(1, 0),
+            Dir4::South => Pos::new(0, 1),
+            Dir4::West => Pos::new(-1, 0),
+        }
+    }
+
+    /// The direction of a unit step, if `offset` is one.
+    pub fn from_offset(offset: Vec2) -> Option<Self> {
+        Self::iter().find(|d| d.offset() == offset)
+    }
+
+    /// `^`, `>`, `v` or `<`.
+    pub fn arrow(self) -> char {
+        b"^>v<"[self as usize] as char
+    }
+
+    /// `N`, `E`, `S` or `W`.
+    pub fn letter(self) -> char {
+        b"NESW"[self as usize] as char
+    }
+}
+
+/// Parses arrows (`^>v<`) and compass letters (`NESW`, either case).
+impl TryFrom<char> for Dir4 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        Self::iter()
+            .find(|d| d.arrow() == c || d.letter() == c.to_ascii_uppercase())
+            .ok_or(format!("unknown direction {:?}", c))
+    }
+}
+
+impl std::str::FromStr for Dir4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(format!("unknown direction {:?}", s)),
+        }
+    }
+}
+
+/// Prints the arrow; use [`Dir4::letter`] for the compass letter.
+impl std::fmt::Display for Dir4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight straight and diagonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    const LETTERS: [&'static str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+    pub fn iter() -> impl Iterator<Item = Dir8> {
+        Self::ALL.into_iter()
+    }
+
+    /// Turns by 45°.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45°.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir8::North => Pos::new(0, -1),
+            Dir8::NorthEast => Pos::new(1, -1),
+            Dir8::East => Pos::new(1, 0),
+            Dir8::SouthEast => Pos::new(1, 1),
+            Dir8::South => Pos::new(0, 1),
+            Dir8::SouthWest => Pos::new(-1, 1),
+            Dir8::West => Pos::new(-1, 0),
+            Dir8::NorthWest => Pos::new(-1, -1),
+        }
+    }
+
+    pub fn from_offset(offset: Vec2) -> Option<Self> {
+        Self::iter().find(|d| d.offset() == offset)
+    }
+
+    /// `↑`, `↗`, `→`, `↘`, `↓`, `↙`, `←` or `↖`.
+    pub fn arrow(self) -> char {
+        ['↑', '↗', '→', '↘', '↓', '↙', '←', '↖'][self as usize]
+    }
+
+    /// `N`, `NE`, `E`, ... `NW`.
+    pub fn letters(self) -> &'static str {
+        Self::LETTERS[self as usize]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        Self::ALL[d as usize * 2]
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = Dir8;
+
+    fn try_from(d: Dir8) -> Result<Self, Dir8> {
+        if d.is_diagonal() {
+            Err(d)
+        } else {
+            Ok(Dir4::ALL[d as usize / 2])
+        }
+    }
+}
+
+/// Parses the arrows of [`Dir8::arrow`] and [`Dir4::arrow`], and compass letters.
+impl TryFrom<char> for Dir8 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        match Self::iter().find(|d| d.arrow() == c) {
+            Some(d) => Ok(d),
+            None => Dir4::try_from(c).map(Dir8::from),
+        }
+    }
+}
+
+/// Parses compass letters like `NE` or `sw`, and single arrows.
+impl std::str::FromStr for Dir8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let upper = s.to_ascii_uppercase();
+        if let Some(d) = Self::iter().find(|d| d.letters() == upper) {
+            return Ok(d);
+        }
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(format!("unknown direction {:?}", s)),
+        }
+    }
+}
+
+/// Prints the compass letters, which unlike the arrows are plain ASCII.
+impl std::fmt::Display for Dir8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.letters())
+    }
+}
+
+impl From<Dir4> for Vec2 {
+    fn from(d: Dir4) -> Self {
+        d.offset()
+    }
+}
+
+impl From<Dir8> for Vec2 {
+    fn from(d: Dir8) -> Self {
+        d.offset()
+    }
+}
+
+impl Add<Dir4> for Pos {
+    type Output = Pos;
+
+    fn add(self, d: Dir4) -> Pos {
+        self + d.offset()
+    }
+}
+
+impl Add<Dir8> for Pos {
+    type Output = Pos;
+
+    fn add(self, d: Dir8) -> Pos {
+        self + d.offset()
+    }
+}
+
+impl AddAssign<Dir4> for Pos {
+    fn add_assign(&mut self, d: Dir4) {
+        *self = *self + d;
+    }
+}
+
+/// Which axes wrap around, joining opposite edges.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wrap {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl Wrap {
+    pub const NONE: Wrap = Wrap { x: false, y: false };
+    pub const TORUS: Wrap = Wrap { x: true, y: true };
+    /// Leaving through the left edge enters through the right one.
+    pub const X: Wrap = Wrap { x: true, y: false };
+    /// Leaving through the top edge enters through the bottom one.
+    pub const Y: Wrap = Wrap { x: false, y: true };
+}
+
+/// A `width` by `height` rectangle of positions, possibly wrapping around.
+///
+/// Positions past a wrapping edge are moved back inside; positions past
+/// any other edge do not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Topology {
+    pub width: usize,
+    pub height: usize,
+    pub wrap: Wrap,
+}
+
+impl Topology {
+    pub fn new(width: usize, height: usize, wrap: Wrap) -> Self {
+        Self {
+            width,
+            height,
+            wrap,
+        }
+    }
+
+    pub fn torus(width: usize, height: usize) -> Self {
+        Self::new(width, height, Wrap::TORUS)
+    }
+
+    /// The position `pos` refers to inside the rectangle, if any.
+    pub fn wrap(&self, pos: Pos) -> Option<Pos> {
+        fn axis(v: isize, len: usize, wraps: bool) -> Option<isize> {
+            if (0..len as isize).contains(&v) {
+                Some(v)
+            } else if wraps && len > 0 {
+                Some(v.rem_euclid(len as isize))
+            } else {
+                None
+            }
+        }
+        Some(Pos::new(
+            axis(pos.x, self.width, self.wrap.x)?,
+            axis(pos.y, self.height, self.wrap.y)?,
+        ))
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.wrap(pos).is_some()
+    }
+
+    /// Moves `pos` by `offset`, which may be any number of laps.
+    pub fn step(&self, pos: Pos, offset: Vec2) -> Option<Pos> {
+        self.wrap(pos + offset)
+    }
+
+    /// The shortest offset leading from `from` to `to`.
+    pub fn delta(&self, from: Pos, to: Pos) -> Vec2 {
+        fn axis(d: isize, len: usize, wraps: bool) -> isize {
+            let len = len as isize;
+            if !wraps || len == 0 {
+                return d;
+            }
+            let d = d.rem_euclid(len);
+            if d > len / 2 {
+                d - len
+            } else {
+                d
+            }
+        }
+        let d = to - from;
+        Pos::new(
+            axis(d.x, self.width, self.wrap.x),
+            axis(d.y, self.height, self.wrap.y),
+        )
+    }
+
+    /// The manhattan distance, going round where that is shorter.
+    pub fn manhattan(&self, a: Pos, b: Pos) -> isize {
+        self.delta(a, b).manhattan(Pos::ZERO)
+    }
+
+    /// The chebyshev distance, going round where that is shorter.
+    pub fn chebyshev(&self, a: Pos, b: Pos) -> isize {
+        self.delta(a, b).chebyshev(Pos::ZERO)
+    }
+
+    /// Positions at the `stencil` offsets from `pos`.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        stencil: &'a [Vec2],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        stencil.iter().filter_map(move |d| self.step(pos, *d))
+    }
+}
+
+/// A rectangular grid, stored row by row. Positions are `(x, y)` with `y` the row.
+///
+/// Grids do not wrap around unless made to with [`Grid::with_wrap`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    wrap: Wrap,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(width, height, vec![fill; width * height])
+    }
+
+    /// Panics unless `cells` holds exactly `width * height` cells.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "{} cells do not fill a {}x{} grid",
+            cells.len(),
+            width,
+            height
+        );
+        Self {
+            width,
+            height,
+            wrap: Wrap::NONE,
+            cells,
+        }
+    }
+
+    /// Makes positions past the edges along `wrap`ping axes refer to the
+    /// cells on the opposite side, for indexing and neighbours alike.
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn topology(&self) -> Topology {
+        Topology::new(self.width, self.height, self.wrap)
+    }
+
+    /// Panics if the rows differ in length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for row in rows {
+            let w = *width.get_or_insert(row.len());
+            assert_eq!(
+                row.len(),
+                w,
+                "row {} has {} cells, not {}",
+                height,
+                row.len(),
+                w
+            );
+            cells.extend(row);
+            height += 1;
+        }
+        Self::from_vec(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` refers to a cell, which it always does along wrapping axes.
+    pub fn in_bounds(&self, pos: impl Into<Pos>) -> bool {
+        self.index_of(pos.into()).is_some()
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let Pos { x, y } = self.topology().wrap(pos)?;
+        Some(y as usize * self.width + x as usize)
+    }
+
+    pub fn get(&self, pos: impl Into<Pos>) -> Option<&T> {
+        self.index_of(pos.into()).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Pos>) -> Option<&mut T> {
+        self.index_of(pos.into()).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero width grids.
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} of a {} wide grid", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::from((x, y))))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+            .with_wrap(self.wrap)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_rows(self.columns().map(|c| c.cloned().collect())).with_wrap(Wrap {
+            x: self.wrap.y,
+            y: self.wrap.x,
+        })
+    }
+}
+
+impl<T, P: Into<Pos>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: P) -> &T {
+        let pos = pos.into();
+        let i = self.index_of(pos);
+        &self.cells[i.unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })]
+    }
+}
+
+impl<T, P: Into<Pos>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, pos: P) -> &mut T {
+        let pos = pos.into();
+        let i = self.index_of(pos);
+        &mut self.cells[i.unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })]
+    }
+}
+
+/// Offsets of the four orthogonal neighbours.
+pub const STRAIGHT: [Vec2; 4] = [
+    Pos::new(1, 0),
+    Pos::new(-1, 0),
+    Pos::new(0, 1),
+    Pos::new(0, -1),
+];
+
+/// Offsets of the four diagonal neighbours.
+pub const DIAGONAL: [Vec2; 4] = [
+    Pos::new(1, 1),
+    Pos::new(-1, 1),
+    Pos::new(-1, -1),
+    Pos::new(1, -1),
+];
+
+/// Offsets of all eight surrounding cells.
+pub const ALL_AROUND: [Vec2; 8] = [
+    Pos::new(1, 0),
+    Pos::new(1, 1),
+    Pos::new(0, 1),
+    Pos::new(-1, 1),
+    Pos::new(-1, 0),
+    Pos::new(-1, -1),
+    Pos::new(0, -1),
+    Pos::new(1, -1),
+];
+
+/// Positions at the `stencil` offsets from `pos` that lie inside `square`,
+/// wrapped around if it wraps.
+pub fn neighbour_pos<'a, T>(
+    square: &'a Grid<T>,
+    pos: Pos,
+    stencil: &'a [Vec2],
+) -> impl Iterator<Item = Pos> + 'a {
+    let topology = square.topology();
+    stencil.iter().filter_map(move |d| topology.step(pos, *d))
+}
+
+/// Cells at the `stencil` offsets from `pos` accepted by `is_neighbour`.
+pub fn neighbours<'a, T>(
+    square: &'a Grid<T>,
+    pos: Pos,
+    stencil: &'a [Vec2],
     is_neighbour: impl Fn(Pos, &T) -> bool + 'a,
 ) -> impl Iterator<Item = (Pos, &'a T)>
 where
     T: Sized + 'a,
 {
-    straight_neighbour_pos(square, (x, y)).filter_map(move |(x, y)| {
-        let v = &square[y as usize][x as usize];
-        if is_neighbour((x, y), v) {
-            Some(((x, y), v))
+    neighbour_pos(square, pos, stencil).filter_map(move |p| {
+        let v = &square[p];
+        if is_neighbour(p, v) {
+            Some((p, v))
         } else {
             None
         }
     })
 }
 
-pub fn straight_neighbour_pos<'a, T>(
-    square: &'a M<T>,
-    (x, y): Pos,
-) -> impl Iterator<Item = Pos> + 'a {
-    [(1, 0), (-1, 0), (0, 1), (0, -1)]
-        .iter()
-        .filter_map(move |(dx, dy)| {
-            if x == 0 && *dx < 0 || y == 0 && *dy < 0 {
-                return None;
+pub fn straight_neighbours<'a, T>(
+    square: &'a Grid<T>,
+    pos: Pos,
+    is_neighbour: impl Fn(Pos, &T) -> bool + 'a,
+) -> impl Iterator<Item = (Pos, &'a T)>
+where
+    T: Sized + 'a,
+{
+    neighbours(square, pos, &STRAIGHT, is_neighbour)
+}
+
+pub fn straight_neighbour_pos<T>(square: &Grid<T>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    neighbour_pos(square, pos, &STRAIGHT)
+}
+
+pub fn diagonal_neighbours<'a, T>(
+    square: &'a Grid<T>,
+    pos: Pos,
+    is_neighbour: impl Fn(Pos, &T) -> bool + 'a,
+) -> impl Iterator<Item = (Pos, &'a T)>
+where
+    T: Sized + 'a,
+{
+    neighbours(square, pos, &DIAGONAL, is_neighbour)
+}
+
+pub fn diagonal_neighbour_pos<T>(square: &Grid<T>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    neighbour_pos(square, pos, &DIAGONAL)
+}
+
+pub fn all_neighbours<'a, T>(
+    square: &'a Grid<T>,
+    pos: Pos,
+    is_neighbour: impl Fn(Pos, &T) -> bool + 'a,
+) -> impl Iterator<Item = (Pos, &'a T)>
+where
+    T: Sized + 'a,
+{
+    neighbours(square, pos, &ALL_AROUND, is_neighbour)
+}
+
+pub fn all_neighbour_pos<T>(square: &Grid<T>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    neighbour_pos(square, pos, &ALL_AROUND)
+}
+
+/// Cells reachable from `start` through `stencil` steps from a cell `a` to
+/// a cell `b` where `connected(a, b)` holds, in the order they are found.
+pub fn flood_fill<T>(
+    square: &Grid<T>,
+    start: Pos,
+    stencil: &[Vec2],
+    connected: impl Fn(&T, &T) -> bool,
+) -> Vec<Pos> {
+    let mut seen = Grid::new(square.width(), square.height(), false);
+    seen[start] = true;
+    let mut found = vec![start];
+    let mut next = 0;
+    while let Some(&pos) = found.get(next) {
+        next += 1;
+        for p in neighbour_pos(square, pos, stencil) {
+            if !seen[p] && connected(&square[pos], &square[p]) {
+                seen[p] = true;
+                found.push(p);
             }
-            if (x + dx) >= square.len() as isize || (y + dy) >= square[0].len() as isize {
-                return None;
+        }
+    }
+    found
+}
+
+/// The connected components of a grid, see [`components`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Components {
+    /// Index into `cells` of the component each cell belongs to.
+    pub labels: Grid<usize>,
+    /// Cells of each component, components ordered by their first cell.
+    pub cells: Vec<Vec<Pos>>,
+}
+
+impl Components {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells in the same component as `pos`.
+    pub fn component_of(&self, pos: impl Into<Pos>) -> &[Pos] {
+        &self.cells[self.labels[pos]]
+    }
+}
+
+/// Labels every cell with its component, joining cells at `stencil` offsets
+/// whose values are `connected`. Use [`STRAIGHT`] for 4-connectivity and
+/// [`ALL_AROUND`] for 8-connectivity; `connected` should be symmetric.
+pub fn components<T>(
+    square: &Grid<T>,
+    stencil: &[Vec2],
+    connected: impl Fn(&T, &T) -> bool,
+) -> Components {
+    let mut labels = Grid::new(square.width(), square.height(), usize::MAX);
+    let mut cells = Vec::new();
+    for pos in square.positions() {
+        if labels[pos] != usize::MAX {
+            continue;
+        }
+        let component = flood_fill(square, pos, stencil, &connected);
+        for p in &component {
+            labels[*p] = cells.len();
+        }
+        cells.push(component);
+    }
+    Components { labels, cells }
+}
+
+/// A set of cells, such as one of the [`Components`] of a grid.
+///
+/// Cell `(x, y)` covers the unit square from corner `(x, y)` to corner
+/// `(x + 1, y + 1)`, which is what [`Region::outline`] is measured in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<Pos>,
+}
+
+impl Region {
+    pub fn new(cells: impl IntoIterator<Item = Pos>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains(&pos)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell sides facing a cell outside the region.
+    pub fn perimeter(&self) -> usize {
+        self.cells()
+            .map(|p| Dir4::iter().filter(|d| !self.contains(p + *d)).count())
+            .sum()
+    }
+
+    /// Number of corners of the outline, including the outlines of holes.
+    ///
+    /// Where the region touches itself diagonally, the shared point counts
+    /// as two corners.
+    pub fn corners(&self) -> usize {
+        self.cells()
+            .map(|p| {
+                Dir4::iter()
+                    .filter(|d| {
+                        let (a, b) = (self.contains(p + *d), self.contains(p + d.turn_right()));
+                        let diagonal = self.contains(p + *d + d.turn_right());
+                        // Outward corners, then inward ones.
+                        (!a && !b) || (a && b && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight sides of the outline, which has as many sides as corners.
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// The top left and bottom right cells of the smallest rectangle
+    /// holding the region.
+    pub fn bounding_box(&self) -> Option<(Pos, Pos)> {
+        let min_x = self.cells().map(|p| p.x).min()?;
+        let min_y = self.cells().map(|p| p.y).min()?;
+        let max_x = self.cells().map(|p| p.x).max()?;
+        let max_y = self.cells().map(|p| p.y).max()?;
+        Some((Pos::new(min_x, min_y), Pos::new(max_x, max_y)))
+    }
+
+    /// Groups of cells outside the region that are enclosed by it, each
+    /// connected through straight steps.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some((min, max)) = self.bounding_box() else {
+            return Vec::new();
+        };
+        // A margin of one cell connects everything outside into one component.
+        let origin = min - Pos::new(1, 1);
+        let size = max - min + Pos::new(3, 3);
+        let mut grid = Grid::new(size.x as usize, size.y as usize, false);
+        for p in self.cells() {
+            grid[p - origin] = true;
+        }
+        let components = components(&grid, &STRAIGHT, |a, b| a == b);
+        let outside = components.labels[Pos::ZERO];
+        components
+            .cells
+            .into_iter()
+            .enumerate()
+            .filter(|(label, cells)| *label != outside && !grid[cells[0]])
+            .map(|(_, cells)| Region::new(cells.into_iter().map(|p| p + origin)))
+            .collect()
+    }
+
+    /// The outline as closed loops of corner points, the outer boundary
+    /// first and then one loop per hole.
+    ///
+    /// Loops run clockwise as seen on screen around the region and
+    /// counterclockwise around holes, so the region is always on their right.
+    pub fn outline(&self) -> Vec<Vec<Pos>> {
+        // Unit edges along the boundary by their start point.
+        let mut edges: HashMap<Pos, Vec<Dir4>> = HashMap::new();
+        for p in self.cells() {
+            for d in Dir4::iter().filter(|d| !self.contains(p + *d)) {
+                let heading = d.turn_right();
+                // The corner of the cell where the edge on side `d` starts.
+                let start = p + Pos::new(
+                    (heading == Dir4::South || heading == Dir4::West) as isize,
+                    (heading == Dir4::West || heading == Dir4::North) as isize,
+                );
+                edges.entry(start).or_default().push(heading);
             }
-            return Some((x + dx, y + dy));
-        })
+        }
+
+        let mut loops = Vec::new();
+        while let Some(&start) = edges.keys().min() {
+            // Each point with an edge taken and the heading of that edge.
+            let mut steps: Vec<(Pos, Dir4)> = Vec::new();
+            let mut at = start;
+            let mut heading = edges[&start][0];
+            loop {
+                let out = edges.get_mut(&at).unwrap();
+                // Where the region touches itself diagonally, turning left
+                // keeps the outline of each hole separate.
+                heading = [heading.turn_left(), heading, heading.turn_right()]
+                    .into_iter()
+                    .find(|h| out.contains(h))
+                    .unwrap_or(out[0]);
+                out.retain(|h| *h != heading);
+                if out.is_empty() {
+                    edges.remove(&at);
+                }
+                steps.push((at, heading));
+                at += heading;
+                if at == start {
+                    break;
+                }
+            }
+            let corners = (0..steps.len())
+                .filter(|i| steps[(i + steps.len() - 1) % steps.len()].1 != steps[*i].1)
+                .map(|i| steps[i].0)
+                .collect();
+            loops.push(corners);
+        }
+        loops
+    }
+}
+
+impl FromIterator<Pos> for Region {
+    fn from_iter<I: IntoIterator<Item = Pos>>(cells: I) -> Self {
+        Region::new(cells)
+    }
 }
 
 pub trait PosGet<T> {
-    fn pos_get(&self, pos: Pos) -> T;
+    fn pos_get(&self, pos: impl Into<Pos>) -> T;
 }
 
-impl<T> PosGet<T> for M<T>
+impl<T> PosGet<T> for Grid<T>
 where
     T: Copy,
 {
-    fn pos_get(&self, pos: Pos) -> T {
-        self[pos.1 as usize][pos.0 as usize]
+    fn pos_get(&self, pos: impl Into<Pos>) -> T {
+        self[pos]
     }
 }
 
 pub trait PosSafeGet<T> {
-    fn pos_safe_get(&self, pos: Pos) -> Option<&T>;
+    fn pos_safe_get(&self, pos: impl Into<Pos>) -> Option<&T>;
 }
 
-impl<T> PosSafeGet<T> for M<T> {
-    fn pos_safe_get(&self, pos: Pos) -> Option<&T> {
-        if pos.1 < 0 || pos.0 < 0 {
-            return None;
-        }
-        self.get(pos.1 as usize)?.get(pos.0 as usize)
+impl<T> PosSafeGet<T> for Grid<T> {
+    fn pos_safe_get(&self, pos: impl Into<Pos>) -> Option<&T> {
+        self.get(pos)
     }
 }
 
 pub trait PeekStraight<T> {
-    fn peek_rel(&self, from: Pos, towards: Pos) -> Option<&T>;
+    fn peek_rel(&self, from: Pos, towards: Vec2) -> Option<&T>;
 
     fn peek_north(&self, pos: Pos) -> Option<&T> {
-        self.peek_rel(pos, (0, -1))
+        self.peek_rel(pos, Dir4::North.offset())
     }
     fn peek_south(&self, pos: Pos) -> Option<&T> {
-        self.peek_rel(pos, (0, 1))
+        self.peek_rel(pos, Dir4::South.offset())
     }
     fn peek_east(&self, pos: Pos) -> Option<&T> {
-        self.peek_rel(pos, (1, 0))
+        self.peek_rel(pos, Dir4::East.offset())
     }
     fn peek_west(&self, pos: Pos) -> Option<&T> {
-        self.peek_rel(pos, (-1, 0))
+        self.peek_rel(pos, Dir4::West.offset())
     }
 }
 
 impl<B: PosSafeGet<T>, T> PeekStraight<T> for B {
-    fn peek_rel(&self, from: Pos, towards: Pos) -> Option<&T> {
-        self.pos_safe_get(pos_add::<isize>(from, towards))
+    fn peek_rel(&self, from: Pos, towards: Vec2) -> Option<&T> {
+        self.pos_safe_get(from + towards)
     }
 }
 
 pub trait PosSet<T> {
-    fn pos_set(&mut self, pos: Pos, t: T);
+    fn pos_set(&mut self, pos: impl Into<Pos>, t: T);
 }
 
-impl<T> PosSet<T> for M<T> {
-    fn pos_set(&mut self, pos: Pos, t: T) {
-        self[pos.1 as usize][pos.0 as usize] = t;
+impl<T> PosSet<T> for Grid<T> {
+    fn pos_set(&mut self, pos: impl Into<Pos>, t: T) {
+        self[pos] = t;
     }
 }
 
@@ -102,7 +1039,7 @@ pub trait PosFind<T> {
     fn pos_find(&self, val: T) -> Option<Pos>;
 }
 
-impl<T: Eq + Copy> PosFind<T> for M<T> {
+impl<T: Eq + Copy> PosFind<T> for Grid<T> {
     fn pos_find(&self, val: T) -> Option<Pos> {
         self.pos_iter()
             .filter(|(_, v)| *v == val)
@@ -115,32 +1052,29 @@ pub trait PosIter<T> {
     fn pos_iter(&self) -> impl Iterator<Item = (Pos, T)>;
 }
 
-impl<T> PosIter<T> for M<T>
+impl<T> PosIter<T> for Grid<T>
 where
     T: Copy,
 {
     fn pos_iter(&self) -> impl Iterator<Item = (Pos, T)> {
-        self.iter().enumerate().flat_map(|(i, line)| {
-            line.iter()
-                .enumerate()
-                .map(move |(j, t)| ((j as isize, i as isize), *t))
-        })
+        self.positions().zip(self.cells.iter().copied())
     }
 }
 
-pub fn pos_add<T: Into<isize>>(a: Pos, b: (T, T)) -> Pos {
-    (a.0 + b.0.into(), a.1 + b.1.into())
-}
-
-pub fn pos_sub<T: Into<isize>>(a: Pos, b: (T, T)) -> Pos {
-    (a.0 - b.0.into(), a.1 - b.1.into())
-}
-
 #[derive(Debug, Clone)]
 pub struct Counter<T> {
     pub counts: HashMap<T, isize>,
 }
 
+impl<T> Default for Counter<T>
+where
+    T: Eq + core::hash::Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Counter<T>
 where
     T: Eq + core::hash::Hash,
@@ -159,3 +1093,262 @@ where
         *self.counts.entry(t).or_default() += a
     }
 }
+
+#[test]
+fn test_grid() {
+    let mut grid = Grid::from_rows(["abc", "def"].map(|l| l.chars().collect()));
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2usize, 0)], 'c');
+    assert_eq!(grid.pos_get((0isize, 1)), 'd');
+    assert_eq!(grid.pos_safe_get(Pos::new(3, 0)), None);
+    assert_eq!(grid.pos_safe_get(Pos::new(0, -1)), None);
+    assert!(grid.in_bounds((2usize, 1)) && !grid.in_bounds(Pos::new(1, 2)));
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.pos_find('e'), Some(Pos::new(1, 1)));
+
+    grid.pos_set(Pos::new(1, 1), 'x');
+    assert_eq!(
+        grid.transpose(),
+        Grid::from_rows(["ad", "bx", "cf"].map(|l| l.chars().collect()))
+    );
+    assert_eq!(
+        grid.pos_iter()
+            .filter(|(_, c)| *c > 'c')
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>(),
+        vec![Pos::new(0, 1), Pos::new(1, 1), Pos::new(2, 1)]
+    );
+}
+
+#[test]
+fn test_neighbours() {
+    // Wider than high, so mixing up the axes drops or invents neighbours.
+    let grid = Grid::from_rows(["abcde", "fghij"].map(|l| l.chars().collect()));
+    let sorted = |it: &mut dyn Iterator<Item = Pos>| {
+        let mut v: Vec<Pos> = it.collect();
+        v.sort();
+        v
+    };
+    assert_eq!(
+        sorted(&mut straight_neighbour_pos(&grid, Pos::new(4, 0))),
+        vec![Pos::new(3, 0), Pos::new(4, 1)]
+    );
+    assert_eq!(
+        sorted(&mut straight_neighbour_pos(&grid, Pos::new(3, 1))),
+        vec![Pos::new(2, 1), Pos::new(3, 0), Pos::new(4, 1)]
+    );
+    assert_eq!(
+        sorted(&mut diagonal_neighbour_pos(&grid, Pos::new(4, 1))),
+        vec![Pos::new(3, 0)]
+    );
+    assert_eq!(all_neighbour_pos(&grid, Pos::new(2, 0)).count(), 5);
+    assert_eq!(
+        all_neighbours(&grid, Pos::new(2, 1), |_, c| *c > 'c')
+            .map(|(_, c)| *c)
+            .collect::<String>(),
+        "igd"
+    );
+
+    let knight = [
+        Pos::new(1, 2),
+        Pos::new(2, 1),
+        Pos::new(-1, 2),
+        Pos::new(-2, 1),
+    ];
+    assert_eq!(
+        neighbours(&grid, Pos::new(1, 0), &knight, |_, _| true).collect::<Vec<_>>(),
+        vec![(Pos::new(3, 1), &'i')]
+    );
+}
+
+#[test]
+fn test_pos() {
+    let (a, b) = (Pos::new(3, -1), Pos::new(-2, 4));
+    assert_eq!(a + b, Pos::new(1, 3));
+    assert_eq!(a - b, Pos::new(5, -5));
+    assert_eq!(-a * 2, Pos::new(-6, 2));
+    assert_eq!(a.manhattan(b), 10);
+    assert_eq!(a.chebyshev(b), 5);
+
+    let east = Pos::new(1, 0);
+    assert_eq!(east.rotate_right(), Pos::new(0, 1));
+    assert_eq!(east.rotate_left(), Pos::new(0, -1));
+    assert_eq!(east.rotate_right().rotate_right(), -east);
+
+    let mut p = Pos::from((2usize, 5usize));
+    p += east;
+    p -= Pos::new(0, 5);
+    assert_eq!(<(usize, usize)>::try_from(p), Ok((3, 0)));
+    assert!(<(usize, usize)>::try_from(Pos::new(-1, 0)).is_err());
+    assert_eq!(Pos::from(Vector2::new(4, 2)), Pos::new(4, 2));
+    assert_eq!(<(isize, isize)>::from(p), (3, 0));
+}
+
+#[test]
+fn test_dirs() {
+    assert_eq!(Dir4::North.turn_left(), Dir4::West);
+    assert_eq!(Dir4::West.turn_right(), Dir4::North);
+    assert_eq!(Dir4::East.reverse(), Dir4::West);
+    for d in Dir4::iter() {
+        assert_eq!(d.offset().rotate_right(), d.turn_right().offset());
+        assert_eq!(Dir4::from_offset(d.offset()), Some(d));
+        assert_eq!(Dir4::try_from(d.arrow()), Ok(d));
+        assert_eq!(d.letter().to_string().parse(), Ok(d));
+        assert_eq!(Dir8::from(d).offset(), d.offset());
+    }
+    assert_eq!(Dir4::try_from('s'), Ok(Dir4::South));
+    assert!(Dir4::try_from('x').is_err());
+    assert!("NE".parse::<Dir4>().is_err());
+    assert_eq!(
+        Dir4::iter().map(|d| d.to_string()).collect::<String>(),
+        "^>v<"
+    );
+
+    assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+    assert_eq!(Dir8::SouthWest.reverse(), Dir8::NorthEast);
+    assert_eq!(Dir8::iter().filter(|d| d.is_diagonal()).count(), 4);
+    assert_eq!(Dir8::from_offset(Pos::new(1, 1)), Some(Dir8::SouthEast));
+    assert_eq!("nw".parse(), Ok(Dir8::NorthWest));
+    assert_eq!("<".parse(), Ok(Dir8::West));
+    assert_eq!(Dir8::try_from('↘'), Ok(Dir8::SouthEast));
+    assert_eq!(Dir4::try_from(Dir8::South), Ok(Dir4::South));
+    assert_eq!(Dir4::try_from(Dir8::NorthEast), Err(Dir8::NorthEast));
+    assert_eq!(Dir8::SouthEast.to_string(), "SE");
+
+    assert_eq!(
+        Pos::new(2, 2) + Dir4::North + Dir8::NorthWest,
+        Pos::new(1, 0)
+    );
+}
+
+#[test]
+fn test_components() {
+    let grid = Grid::from_rows(["AAB", "ABB", "BCA"].map(|l| l.chars().collect()));
+    let straight = components(&grid, &STRAIGHT, |a, b| a == b);
+    assert_eq!(straight.len(), 5);
+    assert_eq!(
+        straight.cells[0],
+        vec![Pos::new(0, 0), Pos::new(1, 0), Pos::new(0, 1)]
+    );
+    assert_eq!(straight.labels[(1usize, 1)], 1);
+    assert_eq!(straight.component_of(Pos::new(2, 1)).len(), 3);
+    assert_eq!(straight.component_of(Pos::new(0, 2)), &[Pos::new(0, 2)]);
+
+    // Diagonals join the lone B in the bottom row to the other Bs.
+    let around = components(&grid, &ALL_AROUND, |a, b| a == b);
+    assert_eq!(around.len(), 4);
+    assert_eq!(around.labels[(0usize, 2)], around.labels[(1usize, 1)]);
+
+    let digits = Grid::from_rows(["0123", "1254", "9876"].map(|l| l.bytes().collect()));
+    let uphill = flood_fill(&digits, Pos::ZERO, &STRAIGHT, |a, b| a + 1 == *b);
+    assert_eq!(uphill.len(), 8);
+    assert_eq!(uphill.last(), Some(&Pos::new(2, 1)));
+    assert!(!uphill.contains(&Pos::new(0, 2)));
+}
+
+#[test]
+fn test_region() {
+    let garden = Grid::from_rows(
+        ["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"].map(|l| l.chars().collect()),
+    );
+    let regions = components(&garden, &STRAIGHT, |a, b| a == b);
+    let a = Region::new(regions.component_of(Pos::ZERO).iter().copied());
+    assert_eq!(a.area(), 28);
+    assert_eq!(a.perimeter(), 40);
+    assert_eq!(a.sides(), 12);
+    assert_eq!(a.bounding_box(), Some((Pos::ZERO, Pos::new(5, 5))));
+
+    let mut holes = a.holes();
+    holes.sort_by_key(|h| h.bounding_box());
+    assert_eq!(holes.len(), 2);
+    assert_eq!(
+        holes[0].bounding_box(),
+        Some((Pos::new(1, 3), Pos::new(2, 4)))
+    );
+    assert_eq!((holes[1].area(), holes[1].sides()), (4, 4));
+
+    let outline = a.outline();
+    assert_eq!(outline.len(), 3);
+    assert_eq!(
+        outline[0],
+        vec![
+            Pos::new(0, 0),
+            Pos::new(6, 0),
+            Pos::new(6, 6),
+            Pos::new(0, 6)
+        ]
+    );
+    // Holes run the other way round.
+    assert_eq!(
+        outline[1],
+        vec![
+            Pos::new(1, 3),
+            Pos::new(1, 5),
+            Pos::new(3, 5),
+            Pos::new(3, 3)
+        ]
+    );
+    assert_eq!(outline.iter().map(Vec::len).sum::<usize>(), a.corners());
+
+    // Two L shapes touching diagonally, enclosing the middle cell.
+    let ls: Region = [(0, 0), (1, 0), (0, 1), (2, 1), (1, 2), (2, 2)]
+        .map(|(x, y)| Pos::new(x, y))
+        .into_iter()
+        .collect();
+    assert_eq!(ls.holes(), vec![Region::new([Pos::new(1, 1)])]);
+    assert_eq!(ls.perimeter(), 16);
+    let outline = ls.outline();
+    assert_eq!(outline.len(), 2);
+    assert_eq!(
+        outline[1],
+        vec![
+            Pos::new(1, 1),
+            Pos::new(1, 2),
+            Pos::new(2, 2),
+            Pos::new(2, 1)
+        ]
+    );
+    assert_eq!(outline.iter().map(Vec::len).sum::<usize>(), ls.corners());
+    assert_eq!(Region::default().bounding_box(), None);
+    assert!(Region::default().outline().is_empty());
+}
+
+#[test]
+fn test_wrap() {
+    let torus = Topology::torus(5, 3);
+    assert_eq!(torus.wrap(Pos::new(-1, 3)), Some(Pos::new(4, 0)));
+    assert_eq!(
+        torus.step(Pos::new(2, 1), Pos::new(3, -1) * 100),
+        Some(Pos::new(2, 0))
+    );
+    assert_eq!(
+        torus.delta(Pos::new(0, 0), Pos::new(4, 2)),
+        Pos::new(-1, -1)
+    );
+    assert_eq!(torus.manhattan(Pos::new(0, 0), Pos::new(3, 1)), 3);
+    assert_eq!(torus.chebyshev(Pos::new(0, 0), Pos::new(3, 2)), 2);
+    assert_eq!(torus.neighbours(Pos::ZERO, &STRAIGHT).count(), 4);
+
+    let cylinder = Topology::new(5, 3, Wrap::X);
+    assert_eq!(cylinder.wrap(Pos::new(5, 2)), Some(Pos::new(0, 2)));
+    assert_eq!(cylinder.wrap(Pos::new(0, 3)), None);
+    assert_eq!(cylinder.manhattan(Pos::new(0, 0), Pos::new(4, 2)), 3);
+    assert_eq!(
+        Topology::new(5, 3, Wrap::NONE).manhattan(Pos::ZERO, Pos::new(4, 2)),
+        6
+    );
+
+    let mut grid = Grid::from_rows(["abc", "def"].map(|l| l.chars().collect())).with_wrap(Wrap::Y);
+    assert_eq!(grid[Pos::new(1, -1)], 'e');
+    assert!(grid.in_bounds(Pos::new(0, 7)) && !grid.in_bounds(Pos::new(3, 0)));
+    grid[Pos::new(0, 2)] = 'x';
+    assert_eq!(grid.row(0), &['x', 'b', 'c']);
+    assert_eq!(
+        straight_neighbours(&grid, Pos::ZERO, |_, _| true)
+            .map(|(_, c)| *c)
+            .collect::<String>(),
+        "bdd"
+    );
+    assert_eq!(grid.transpose().topology().wrap, Wrap::X);
+}
@@ -0,0 +1,229 @@
+use std::{fmt, fs, io, path::Path, time::Duration};
+
+/// The server's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous answer; nothing was checked.
+    Wait(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting.
+    pub fn parse(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if html.contains("That's not the right answer") {
+            Some(if html.contains("your answer is too high") {
+                Self::TooHigh
+            } else if html.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if html.contains("You gave an answer too recently") {
+            Some(Self::Wait(
+                parse_wait(html).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Self::Correct => Some("correct"),
+            Self::TooHigh => Some("too-high"),
+            Self::TooLow => Some("too-low"),
+            Self::Wrong => Some("wrong"),
+            Self::Wait(_) | Self::WrongLevel => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [Self::Correct, Self::TooHigh, Self::TooLow, Self::Wrong]
+            .into_iter()
+            .find(|v| v.key() == Some(key))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::Wait(d) => write!(f, "wait {}s before submitting again", d.as_secs()),
+            Self::WrongLevel => write!(f, "part already solved or not unlocked"),
+        }
+    }
+}
+
+/// Reads "You have 1m 4s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let len = html[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for amount in html[start..start + len].split_whitespace() {
+        let (n, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Answers submitted for one day and their verdicts, used to refuse
+/// submissions that are already known to be wrong.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct History {
+    entries: Vec<(u8, Verdict, String)>,
+}
+
+impl History {
+    pub fn parse(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, ' ');
+                let part = fields.next()?.parse().ok()?;
+                let verdict = Verdict::from_key(fields.next()?)?;
+                Some((part, verdict, fields.next()?.to_owned()))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// The history at `path`; empty if nothing was submitted yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Self::parse(&content)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Remembers a verdict; waits and level mismatches say nothing about the answer.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: &Verdict) {
+        if verdict.key().is_some() {
+            self.entries
+                .push((part, verdict.clone(), answer.to_owned()));
+        }
+    }
+
+    /// Why `answer` should not be submitted for `part`, if there is a reason.
+    pub fn refuse(&self, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+        for (_, verdict, previous) in self.entries.iter().filter(|(p, _, _)| *p == part) {
+            // Only numbers can be too high or too low.
+            let numbers = number.zip(previous.parse::<i128>().ok());
+            match verdict {
+                Verdict::Correct => return Some(format!("already solved with {}", previous)),
+                _ if previous == answer => {
+                    return Some(format!("{} was already submitted: {}", answer, verdict))
+                }
+                Verdict::TooHigh if numbers.is_some_and(|(n, prev)| n >= prev) => {
+                    return Some(format!("{} is too high, {} already was", answer, previous))
+                }
+                Verdict::TooLow if numbers.is_some_and(|(n, prev)| n <= prev) => {
+                    return Some(format!("{} is too low, {} already was", answer, previous))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, verdict, answer) in &self.entries {
+            writeln!(f, "{} {} {}", part, verdict.key().unwrap(), answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_verdicts() {
+    let page = |text| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+    assert_eq!(
+        Verdict::parse(&page(
+            "That's the right answer!  You are one gold star closer."
+        )),
+        Some(Verdict::Correct)
+    );
+    assert_eq!(
+        Verdict::parse(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, ..."
+        )),
+        Some(Verdict::TooHigh)
+    );
+    assert_eq!(
+        Verdict::parse(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Some(Verdict::TooLow)
+    );
+    assert_eq!(
+        Verdict::parse(&page("That's not the right answer.  If you're stuck, ...")),
+        Some(Verdict::Wrong)
+    );
+    assert_eq!(
+        Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait.")),
+        Some(Verdict::Wait(Duration::from_secs(64)))
+    );
+    assert_eq!(
+        Verdict::parse(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        )),
+        Some(Verdict::WrongLevel)
+    );
+    assert_eq!(Verdict::parse(&page("Please log in.")), None);
+}
+
+#[test]
+fn test_history() {
+    let mut history = History::default();
+    history.record(1, "100", &Verdict::TooHigh);
+    history.record(1, "10", &Verdict::TooLow);
+    history.record(1, "50", &Verdict::Wrong);
+    history.record(1, "60", &Verdict::Wait(Duration::from_secs(30)));
+    assert!(history.refuse(1, "100").is_some());
+    assert!(history.refuse(1, "120").is_some());
+    assert!(history.refuse(1, "5").is_some());
+    assert!(history.refuse(1, "50").is_some());
+    assert_eq!(history.refuse(1, "60"), None);
+    assert_eq!(history.refuse(2, "100"), None);
+
+    let mut words = History::default();
+    words.record(1, "abc", &Verdict::TooHigh);
+    words.record(1, "xyz", &Verdict::TooLow);
+    assert_eq!(words.refuse(1, "5"), None);
+    assert_eq!(words.refuse(1, "def"), None);
+    assert!(words.refuse(1, "abc").is_some());
+
+    history.record(2, "co,de,ka,ta", &Verdict::Correct);
+    assert!(history.refuse(2, "ta,ka,de,co").is_some());
+    assert_eq!(History::parse(&history.to_string()), history);
+}